@@ -0,0 +1,774 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    ops::AddAssign,
+    path::Path,
+};
+
+use aoc::{Answer, Args, Error, Key, Mode, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const KEY: Key = Key::new(2025, 1);
+
+    // moves are read line by line while solving
+    type Input = String;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "dial simulation, counting landings on 0",
+        "dial simulation, counting landings on and passes over 0",
+    ];
+
+    fn parse(data: &str) -> Result<String, Error> {
+        Ok(data.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer, Error> {
+        Ok(part1(input.as_bytes())?.to_string())
+    }
+
+    fn part2(input: &String) -> Result<Answer, Error> {
+        Ok(part2(input.as_bytes())?.to_string())
+    }
+
+    fn stream(part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        match part {
+            1 => Some(part1(input).map(|zeros| zeros.to_string())),
+            2 => Some(part2(input).map(|zeros| zeros.to_string())),
+            _ => None,
+        }
+    }
+
+    fn modes() -> Vec<Mode> {
+        let flags = &["--trajectory", "--histogram", "--starts", "--lock"];
+        vec![Mode {
+            flags,
+            values: flags,
+            usage: "[--trajectory <path>] [--histogram <path>] [--starts <path>] [--lock <path>]  \
+                    dial analysis",
+            run: analyse_dial,
+        }]
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Move {
+    pub dir: Direction,
+    pub steps: isize,
+}
+
+impl Move {
+    // eg L68 or R14, None for anything else
+    pub fn parse(line: &str) -> Option<Move> {
+        let dir = match line.get(..1)? {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return None,
+        };
+        // at most u32::MAX steps, so no number of moves overflows the click counts
+        let steps = line[1..].parse::<u32>().ok()?;
+
+        Some(Move {
+            dir,
+            steps: steps as isize,
+        })
+    }
+}
+
+impl fmt::Display for Move {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let dir = match self.dir {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{dir}{}", self.steps)
+    }
+}
+
+// the moves of the input one line at a time, blank lines are skipped
+pub fn moves(input: impl BufRead) -> impl Iterator<Item = Result<Move, Error>> {
+    input
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(
+                Move::parse(line.trim())
+                    .ok_or_else(|| Error::at_line(i + 1, format!("bad rotation {line:?}"))),
+            ),
+            Err(e) => Some(Err(e.into())),
+        })
+}
+
+// how often a move pointed the dial at one of its targets
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Clicks {
+    pub landings: isize, // move ended on a target
+    pub passes: isize,   // move clicked past a target without stopping there
+}
+
+impl AddAssign for Clicks {
+    fn add_assign(&mut self, other: Clicks) {
+        self.landings += other.landings;
+        self.passes += other.passes;
+    }
+}
+
+// a single move as it happened on the dial
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Step {
+    pub mv: Move,
+    pub start: isize,
+    pub end: isize,
+    pub clicks: Clicks,
+}
+
+#[derive(Debug, Clone)]
+pub struct Dial {
+    pub size: isize,
+    pub position: isize,
+    pub targets: Vec<isize>,
+}
+
+impl Dial {
+    pub fn new(size: isize, start: isize, targets: &[isize]) -> Self {
+        assert!(size > 0, "dial needs at least one position");
+
+        Dial {
+            size,
+            position: start.rem_euclid(size),
+            targets: targets.iter().map(|t| t.rem_euclid(size)).collect(),
+        }
+    }
+
+    // lap the dial is on relative to target, every change of lap is one click onto the target
+    fn lap(&self, position: isize, target: isize, dir: Direction) -> isize {
+        match dir {
+            // going left from the target means stepping away from it, not crossing it
+            Direction::Left => (position - target - 1).div_euclid(self.size),
+            Direction::Right => (position - target).div_euclid(self.size),
+        }
+    }
+
+    // where a move ends, not wrapped around
+    pub fn end(&self, mv: Move) -> isize {
+        match mv.dir {
+            Direction::Left => self.position - mv.steps,
+            Direction::Right => self.position + mv.steps,
+        }
+    }
+
+    pub fn apply(&mut self, mv: Move) -> Clicks {
+        let end = self.end(mv);
+
+        let mut clicks = Clicks::default();
+
+        for &target in &self.targets {
+            let hits =
+                (self.lap(end, target, mv.dir) - self.lap(self.position, target, mv.dir)).abs();
+            // the last click is the landing, a zero step move never clicks
+            let landed = mv.steps > 0 && end.rem_euclid(self.size) == target;
+
+            clicks.landings += landed as isize;
+            clicks.passes += hits - landed as isize;
+        }
+
+        self.position = end.rem_euclid(self.size);
+
+        clicks
+    }
+
+    // applies the moves one by one, yielding what each of them did
+    pub fn trajectory(mut self, moves: impl Iterator<Item = Move>) -> impl Iterator<Item = Step> {
+        moves.map(move |mv| {
+            let start = self.position;
+            let clicks = self.apply(mv);
+
+            Step {
+                mv,
+                start,
+                end: self.position,
+                clicks,
+            }
+        })
+    }
+
+    // landings and passes for every position at once, without a run per target
+    pub fn histogram(mut self, moves: impl Iterator<Item = Move>) -> Histogram {
+        let size = self.size as usize;
+        let mut landings = vec![0; size];
+        // every full lap clicks onto each position once
+        let mut full_laps = 0;
+        // partial laps cover an arc of positions, tracked as a difference array
+        let mut arcs = vec![0; size + 1];
+
+        for mv in moves {
+            let end = self.end(mv);
+            full_laps += mv.steps.div_euclid(self.size);
+
+            let rest = mv.steps.rem_euclid(self.size) as usize;
+            if rest > 0 {
+                // first position clicked onto after the full laps
+                let first = match mv.dir {
+                    Direction::Left => end.rem_euclid(self.size),
+                    Direction::Right => (self.position + 1).rem_euclid(self.size),
+                } as usize;
+
+                arcs[first] += 1;
+                if first + rest <= size {
+                    arcs[first + rest] -= 1;
+                } else {
+                    // arc wraps past the last position
+                    arcs[0] += 1;
+                    arcs[first + rest - size] -= 1;
+                }
+            }
+
+            if mv.steps > 0 {
+                landings[end.rem_euclid(self.size) as usize] += 1;
+            }
+            self.position = end.rem_euclid(self.size);
+        }
+
+        let mut passes = vec![0; size];
+        let mut arc_count = 0;
+        for position in 0..size {
+            arc_count += arcs[position];
+            // the landing is the last click of a move, not a pass
+            passes[position] = full_laps + arc_count - landings[position];
+        }
+
+        Histogram { landings, passes }
+    }
+}
+
+// landings and passes per dial position
+#[derive(Debug, PartialEq)]
+pub struct Histogram {
+    pub landings: Vec<isize>,
+    pub passes: Vec<isize>,
+}
+
+impl Histogram {
+    pub fn hits(&self, position: usize) -> isize {
+        self.landings[position] + self.passes[position]
+    }
+
+    // lowest position on a tie
+    pub fn most_landed(&self) -> usize {
+        (0..self.landings.len())
+            .rev()
+            .max_by_key(|&p| self.landings[p])
+            .unwrap_or(0)
+    }
+
+    pub fn most_hit(&self) -> usize {
+        (0..self.landings.len())
+            .rev()
+            .max_by_key(|&p| self.hits(p))
+            .unwrap_or(0)
+    }
+
+    pub fn write_csv(&self, out: &mut impl Write) -> std::io::Result<()> {
+        writeln!(out, "position,landings,passes")?;
+        for position in 0..self.landings.len() {
+            writeln!(
+                out,
+                "{},{},{}",
+                position, self.landings[position], self.passes[position]
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// the puzzle dial, 0-99 starting at 50, counting hits on 0
+pub fn puzzle_dial() -> Dial {
+    Dial::new(100, 50, &[0])
+}
+
+// zero counts for every possible start position in one pass, starting at s and
+// counting hits on 0 is the same as starting at 0 and counting hits on -s
+pub fn sweep_starts(size: isize, moves: impl Iterator<Item = Move>) -> Vec<Clicks> {
+    let histogram = Dial::new(size, 0, &[0]).histogram(moves);
+
+    (0..size)
+        .map(|start| {
+            let target = (-start).rem_euclid(size) as usize;
+            Clicks {
+                landings: histogram.landings[target],
+                passes: histogram.passes[target],
+            }
+        })
+        .collect()
+}
+
+// a lock of several named puzzle dials, a dial can be coupled to drive the next one
+// so its wrap-arounds carry into it like on an odometer
+#[derive(Debug, Default)]
+pub struct Lock {
+    pub names: Vec<String>,
+    pub dials: Vec<Dial>,
+    pub drives: Vec<Option<usize>>,
+    pub totals: Vec<Clicks>,
+}
+
+impl Lock {
+    // one entry per line, A:L68 turns dial A, A>B couples A to drive B. like the moves
+    // of the puzzle, blank lines are skipped and entries trimmed
+    pub fn parse(input: impl BufRead) -> Result<Lock, Error> {
+        let mut lock = Lock::default();
+
+        for (i, line) in input.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some((from, to)) = line.split_once('>') {
+                let (Some(from), Some(to)) = (dial_name(from), dial_name(to)) else {
+                    return Err(Error::at_line(i + 1, format!("bad coupling {line:?}")));
+                };
+                lock.couple(from, to).map_err(|e| e.on_line(i + 1))?;
+            } else {
+                let Some(mv) = line.split_once(':').and_then(|(dial, mv)| {
+                    let mv = Move::parse(mv.trim())?;
+                    Some((dial_name(dial)?, mv))
+                }) else {
+                    return Err(Error::at_line(i + 1, format!("bad dial move {line:?}")));
+                };
+                lock.apply(mv.0, mv.1);
+            }
+        }
+
+        Ok(lock)
+    }
+
+    // dials are added on first mention
+    fn dial_index(&mut self, name: &str) -> usize {
+        match self.names.iter().position(|n| n == name) {
+            Some(index) => index,
+            None => {
+                self.names.push(name.to_string());
+                self.dials.push(puzzle_dial());
+                self.drives.push(None);
+                self.totals.push(Clicks::default());
+                self.names.len() - 1
+            }
+        }
+    }
+
+    pub fn couple(&mut self, from: &str, to: &str) -> Result<(), Error> {
+        let from = self.dial_index(from);
+        let to = self.dial_index(to);
+
+        // a carry must not be able to come back around to the dial it started at
+        let mut next = Some(to);
+        while let Some(index) = next {
+            if index == from {
+                return Err(Error::Parse(
+                    "dial coupling loops back on itself".to_string(),
+                ));
+            }
+            next = self.drives[index];
+        }
+
+        self.drives[from] = Some(to);
+        Ok(())
+    }
+
+    pub fn apply(&mut self, name: &str, mv: Move) {
+        let mut index = self.dial_index(name);
+        let mut mv = mv;
+
+        loop {
+            let dial = &mut self.dials[index];
+            // laps past 0, negative when wrapping backwards from 0 to the last position
+            let carry = dial.end(mv).div_euclid(dial.size);
+            self.totals[index] += dial.apply(mv);
+
+            match self.drives[index] {
+                Some(next) if carry != 0 => {
+                    let dir = if carry > 0 {
+                        Direction::Right
+                    } else {
+                        Direction::Left
+                    };
+                    mv = Move {
+                        dir,
+                        steps: carry.abs(),
+                    };
+                    index = next;
+                }
+                _ => break,
+            }
+        }
+    }
+}
+
+// a dial name without the spaces around it, None when there's nothing left
+fn dial_name(name: &str) -> Option<&str> {
+    Some(name.trim()).filter(|name| !name.is_empty())
+}
+
+pub fn part1(input: impl BufRead) -> Result<isize, Error> {
+    Ok(zero_clicks(input)?.landings)
+}
+
+pub fn part2(input: impl BufRead) -> Result<isize, Error> {
+    let clicks = zero_clicks(input)?;
+    Ok(clicks.landings + clicks.passes)
+}
+
+// clicks onto 0 of the puzzle dial over all moves
+fn zero_clicks(input: impl BufRead) -> Result<Clicks, Error> {
+    let mut dial = puzzle_dial();
+    let mut clicks = Clicks::default();
+    for mv in moves(input) {
+        clicks += dial.apply(mv?);
+    }
+
+    Ok(clicks)
+}
+
+// every move of the puzzle dial as CSV, one row per move
+pub fn write_trajectory(input: impl BufRead, out: &mut impl Write) -> Result<(), Error> {
+    writeln!(out, "move,rotation,start,end,zero_passes,landed_on_zero")?;
+
+    let moves: Vec<Move> = moves(input).collect::<Result<_, _>>()?;
+    for (i, step) in puzzle_dial().trajectory(moves.into_iter()).enumerate() {
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            i + 1,
+            step.mv,
+            step.start,
+            step.end,
+            step.clicks.passes,
+            step.clicks.landings > 0
+        )?;
+    }
+
+    Ok(())
+}
+
+// the dial analysis of the flags given, each writes its file and prints a summary
+pub fn analyse_dial(args: &Args, out: &mut dyn Write) -> Result<(), Error> {
+    let input = args.input_path(Day01::KEY);
+    let read_moves = || -> Result<Vec<Move>, Error> { moves(open(&input)?).collect() };
+
+    if let Some(path) = args.value("--trajectory") {
+        let mut csv = create(path)?;
+        write_trajectory(open(&input)?, &mut csv)?;
+        csv.flush()?;
+    }
+
+    if let Some(path) = args.value("--histogram") {
+        let histogram = puzzle_dial().histogram(read_moves()?.into_iter());
+        let mut csv = create(path)?;
+        histogram.write_csv(&mut csv)?;
+        csv.flush()?;
+
+        let landed = histogram.most_landed();
+        let hit = histogram.most_hit();
+        writeln!(
+            out,
+            "Most landed on: {} ({} times)",
+            landed, histogram.landings[landed]
+        )?;
+        writeln!(out, "Most hit: {} ({} times)", hit, histogram.hits(hit))?;
+    }
+
+    if let Some(path) = args.value("--starts") {
+        let sweep = sweep_starts(100, read_moves()?.into_iter());
+        let part1_counts: Vec<isize> = sweep.iter().map(|c| c.landings).collect();
+        let part2_counts: Vec<isize> = sweep.iter().map(|c| c.landings + c.passes).collect();
+
+        let mut csv = create(path)?;
+        writeln!(csv, "start,part1,part2")?;
+        for start in 0..sweep.len() {
+            writeln!(
+                csv,
+                "{},{},{}",
+                start, part1_counts[start], part2_counts[start]
+            )?;
+        }
+        csv.flush()?;
+
+        for (part, counts) in [(1, &part1_counts), (2, &part2_counts)] {
+            // lowest start position on a tie, every dial has positions
+            let highest = (0..counts.len()).rev().max_by_key(|&s| counts[s]).unwrap();
+            let lowest = (0..counts.len()).min_by_key(|&s| counts[s]).unwrap();
+            writeln!(
+                out,
+                "Part {part}: highest {} from start {highest}, lowest {} from start {lowest}",
+                counts[highest], counts[lowest]
+            )?;
+        }
+    }
+
+    if let Some(path) = args.value("--lock") {
+        let lock = Lock::parse(open(Path::new(path))?)?;
+
+        for (name, clicks) in lock.names.iter().zip(&lock.totals) {
+            writeln!(
+                out,
+                "Dial {}: {} landings, {} passes",
+                name, clicks.landings, clicks.passes
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+fn open(path: &Path) -> Result<BufReader<File>, Error> {
+    let file = File::open(path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+    Ok(BufReader::new(file))
+}
+
+fn create(path: &str) -> Result<BufWriter<File>, Error> {
+    let file = File::create(path).map_err(|e| Error::Io(format!("{path}: {e}")))?;
+    Ok(BufWriter::new(file))
+}
+
+#[test]
+fn test_p1() {
+    let data = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 3)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    assert_eq!(part2(data.as_bytes()).unwrap(), 6)
+}
+
+#[test]
+fn test_bad_moves() {
+    let err = part1("L68\n\nX5\n".as_bytes()).unwrap_err();
+    assert_eq!(err.to_string(), "bad input, line 3: bad rotation \"X5\"");
+
+    assert!(part2("L-5".as_bytes()).is_err());
+    assert!(part2("R99999999999".as_bytes()).is_err());
+    assert!(Lock::parse("A>B\nB>A".as_bytes()).is_err())
+}
+
+#[test]
+fn test_dial_targets() {
+    // 10 position dial from 5, R7 passes 7 and 9 and stops on 2,
+    // L25 laps past both twice, then passes 9 once more and lands on 7
+    let mut dial = Dial::new(10, 5, &[7, 9]);
+
+    assert_eq!(
+        dial.apply(Move::parse("R7").unwrap()),
+        Clicks {
+            landings: 0,
+            passes: 2
+        }
+    );
+    assert_eq!(
+        dial.apply(Move::parse("L25").unwrap()),
+        Clicks {
+            landings: 1,
+            passes: 5
+        }
+    );
+    assert_eq!(dial.position, 7)
+}
+
+#[test]
+fn test_trajectory() {
+    let data = r"L68
+L30
+R48";
+
+    let mut out = Vec::new();
+    write_trajectory(data.as_bytes(), &mut out).unwrap();
+
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        r"move,rotation,start,end,zero_passes,landed_on_zero
+1,L68,50,82,1,false
+2,L30,82,52,0,false
+3,R48,52,0,0,true
+"
+    )
+}
+
+#[test]
+fn test_histogram() {
+    let data = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    let histogram = puzzle_dial().histogram(moves(data.as_bytes()).map(Result::unwrap));
+
+    assert_eq!(histogram.landings[0], 3);
+    assert_eq!(histogram.hits(0), 6);
+
+    // every position agrees with a dial targeting only that position
+    for target in 0..100 {
+        let clicks = Dial::new(100, 50, &[target])
+            .trajectory(moves(data.as_bytes()).map(Result::unwrap))
+            .fold(Clicks::default(), |total, step| Clicks {
+                landings: total.landings + step.clicks.landings,
+                passes: total.passes + step.clicks.passes,
+            });
+
+        assert_eq!(histogram.landings[target as usize], clicks.landings);
+        assert_eq!(histogram.passes[target as usize], clicks.passes);
+    }
+}
+
+#[test]
+fn test_lock() {
+    // A laps three times and carries into B, C is left alone
+    let data = r"A>B
+A:R250
+B:L2
+C:L50";
+
+    let lock = Lock::parse(data.as_bytes()).unwrap();
+
+    assert_eq!(lock.names, ["A", "B", "C"]);
+    assert_eq!(
+        lock.totals,
+        [
+            Clicks {
+                landings: 1,
+                passes: 2
+            },
+            Clicks::default(),
+            Clicks {
+                landings: 1,
+                passes: 0
+            }
+        ]
+    );
+    assert_eq!(lock.dials[1].position, 51);
+
+    // borrowing backwards past 0 turns the next dial back
+    let lock = Lock::parse("A>B\nA:L51".as_bytes()).unwrap();
+
+    assert_eq!(lock.dials[0].position, 99);
+    assert_eq!(lock.dials[1].position, 49);
+
+    // read like the puzzle moves, blank lines skipped and entries trimmed
+    let lock = Lock::parse(" A > B \n\nA: L51\n  \n".as_bytes()).unwrap();
+
+    assert_eq!(lock.names, ["A", "B"]);
+    assert_eq!(lock.dials[1].position, 49);
+    assert_eq!(
+        Lock::parse("A:R5\n:L5".as_bytes()).unwrap_err().to_string(),
+        "bad input, line 2: bad dial move \":L5\""
+    );
+    assert!(Lock::parse("A>".as_bytes()).is_err())
+}
+
+#[test]
+fn test_sweep_starts() {
+    let data = r"L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82";
+
+    let sweep = sweep_starts(100, moves(data.as_bytes()).map(Result::unwrap));
+
+    for start in 0..100 {
+        let mut clicks = Clicks::default();
+        for step in
+            Dial::new(100, start, &[0]).trajectory(moves(data.as_bytes()).map(Result::unwrap))
+        {
+            clicks += step.clicks;
+        }
+
+        assert_eq!(sweep[start as usize], clicks);
+    }
+
+    assert_eq!(sweep[50].landings, 3);
+    assert_eq!(sweep[50].landings + sweep[50].passes, 6)
+}
+
+#[test]
+fn test_analyse_dial() {
+    let dir = std::env::temp_dir();
+    let lock = dir.join(format!("aoc2025-day01-lock-{}.txt", std::process::id()));
+    let trajectory = dir.join(format!(
+        "aoc2025-day01-trajectory-{}.csv",
+        std::process::id()
+    ));
+    std::fs::write(&lock, "A>B\nA:R250\n").unwrap();
+
+    // the way the day's binary and aoc run pick the mode
+    let args = Args::new(
+        [
+            "--input",
+            Day01::KEY.fixture_path("example").to_str().unwrap(),
+            "--lock",
+            lock.to_str().unwrap(),
+            "--trajectory",
+            trajectory.to_str().unwrap(),
+        ]
+        .map(String::from)
+        .to_vec(),
+    );
+    let mut out = Vec::new();
+    let result = aoc::Puzzle::run_mode(&aoc::Day::<Day01>::new(), &args, &mut out);
+
+    let csv = std::fs::read_to_string(&trajectory);
+    std::fs::remove_file(&lock).unwrap();
+    std::fs::remove_file(&trajectory).unwrap();
+
+    assert_eq!(result, Some(Ok(())));
+    assert_eq!(
+        String::from_utf8(out).unwrap(),
+        "Dial A: 1 landings, 2 passes\nDial B: 0 landings, 0 passes\n"
+    );
+    let csv = csv.unwrap();
+    assert_eq!(csv.lines().count(), 11);
+    assert_eq!(csv.lines().nth(1), Some("1,L68,50,82,1,false"))
+}
@@ -0,0 +1,5 @@
+use aoc2025_day01::Day01;
+
+fn main() {
+    aoc::main::<Day01>();
+}
@@ -0,0 +1,1047 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader, Write},
+    ops::RangeInclusive,
+};
+
+use aoc::{Answer, Args, Error, Key, Mode, Solution, debug, ranges::merge_ranges, trace, warn};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const KEY: Key = Key::new(2025, 2);
+
+    // ranges are read one at a time while solving
+    type Input = String;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "repeat multipliers, arithmetic series per block length",
+        "primitive blocks by mobius inversion over block lengths",
+    ];
+
+    fn parse(data: &str) -> Result<String, Error> {
+        Ok(data.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer, Error> {
+        Ok(part1(input.as_bytes())?.to_string())
+    }
+
+    fn part2(input: &String) -> Result<Answer, Error> {
+        Ok(part2(input.as_bytes())?.to_string())
+    }
+
+    fn stream(part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        match part {
+            1 => Some(part1(input).map(|sum| sum.to_string())),
+            2 => Some(part2(input).map(|sum| sum.to_string())),
+            _ => None,
+        }
+    }
+
+    fn modes() -> Vec<Mode> {
+        vec![Mode {
+            flags: &QUERY_FLAGS,
+            values: &["--reps", "--block", "--base", "--overlap", "--format"],
+            usage: "--reps <k|k+|min-max> or --part <1|2>, with [--primitive] [--block <n|min-max>] \
+                    [--base <2-36>] [--overlap <set|multiset>] [--list|--per-range] \
+                    [--format <text|csv|json>]  invalid ids under other rules",
+            run: |args, out| match Query::from_args(args)? {
+                Some(query) => run_query(args, &query, out),
+                None => Ok(()),
+            },
+        }]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeError {
+    // an entry of the range list that isn't start-end
+    Bad {
+        offset: usize, // byte offset of the entry in the input
+        entry: String,
+    },
+    // reading the input failed after offset bytes, the list ends there
+    Io {
+        offset: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RangeError::Bad { offset, entry } => write!(f, "bad range {entry:?} at byte {offset}"),
+            RangeError::Io { offset, message } => write!(f, "{message} at byte {offset}"),
+        }
+    }
+}
+
+impl From<RangeError> for Error {
+    fn from(e: RangeError) -> Self {
+        match e {
+            RangeError::Bad { .. } => Error::Parse(e.to_string()),
+            RangeError::Io { .. } => Error::Io(e.to_string()),
+        }
+    }
+}
+
+// a base ids are written in, 2 to 36 like u64::from_str_radix takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base(u32);
+
+impl Base {
+    pub const DECIMAL: Base = Base(10);
+
+    pub fn new(radix: u32) -> Result<Base, Error> {
+        if (2..=36).contains(&radix) {
+            Ok(Base(radix))
+        } else {
+            Err(Error::Unsupported(format!(
+                "bases are 2 to 36, not {radix}"
+            )))
+        }
+    }
+
+    pub fn radix(self) -> u32 {
+        self.0
+    }
+}
+
+// reads the comma separated range list one entry at a time, so the solver can start
+// before the whole line is in. whitespace and newlines around entries are ignored. a
+// failed read ends the list after its error
+pub struct RangeReader<R> {
+    input: R,
+    base: Base,
+    // bytes read so far
+    offset: usize,
+    buf: Vec<u8>,
+    failed: bool,
+}
+
+impl<R: BufRead> RangeReader<R> {
+    // ranges written in the given base
+    pub fn new(input: R, base: Base) -> Self {
+        RangeReader {
+            input,
+            base,
+            offset: 0,
+            buf: Vec::new(),
+            failed: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for RangeReader<R> {
+    type Item = Result<RangeInclusive<u64>, RangeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.failed {
+            self.buf.clear();
+            let entry_offset = self.offset;
+            let read = match self.input.read_until(b',', &mut self.buf) {
+                Ok(0) => return None,
+                Ok(read) => read,
+                Err(e) => {
+                    self.failed = true;
+                    return Some(Err(RangeError::Io {
+                        offset: entry_offset,
+                        message: e.to_string(),
+                    }));
+                }
+            };
+            self.offset += read;
+
+            let entry = self.buf.strip_suffix(b",").unwrap_or(&self.buf);
+            let leading = entry.iter().take_while(|b| b.is_ascii_whitespace()).count();
+            let entry = entry.trim_ascii();
+
+            // nothing between two commas or after the last one
+            if entry.is_empty() {
+                continue;
+            }
+
+            let range = str::from_utf8(entry).ok().and_then(|r| {
+                let (start, end) = r.split_once('-')?;
+                let start = u64::from_str_radix(start, self.base.radix()).ok()?;
+                let end = u64::from_str_radix(end, self.base.radix()).ok()?;
+                Some(start..=end)
+            });
+
+            return Some(range.ok_or_else(|| RangeError::Bad {
+                offset: entry_offset + leading,
+                entry: String::from_utf8_lossy(entry).into_owned(),
+            }));
+        }
+
+        None
+    }
+}
+
+// all well formed ranges, malformed entries are reported on stderr and skipped, a failed
+// read is reported and ends the list
+pub fn read_ranges(input: impl BufRead, base: Base) -> Vec<RangeInclusive<u64>> {
+    RangeReader::new(input, base)
+        .filter_map(|range| range.map_err(|e| warn!("skipping {e}")).ok())
+        .collect()
+}
+
+pub fn part1(input: impl BufRead) -> Result<u128, Error> {
+    // block repeated exactly twice, 1111 still counts as 11 twice
+    matching_sum(input, &Query::new(Repetition::Exactly(2)))
+}
+
+pub fn part2(input: impl BufRead) -> Result<u128, Error> {
+    matching_sum(input, &Query::new(Repetition::AtLeast(2)))
+}
+
+// sum of the matching ids of every range, the first malformed entry is an error
+fn matching_sum(input: impl BufRead, query: &Query) -> Result<u128, Error> {
+    let mut sum = 0;
+    for range in RangeReader::new(input, Base::DECIMAL) {
+        sum += tally(range?, query).sum;
+    }
+
+    Ok(sum)
+}
+
+// how many times a block has to repeat to make an id
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Repetition {
+    Exactly(u32),
+    AtLeast(u32),
+    Between(u32, u32), // inclusive
+}
+
+impl Repetition {
+    // 3 is exactly, 3+ at least and 2-4 between
+    pub fn parse(s: &str) -> Result<Repetition, Error> {
+        let repetition = if let Some(k) = s.strip_suffix('+') {
+            k.parse().ok().map(Repetition::AtLeast)
+        } else if let Some((min, max)) = s.split_once('-') {
+            parse_span(min, max).map(|(min, max)| Repetition::Between(min, max))
+        } else {
+            s.parse().ok().map(Repetition::Exactly)
+        };
+
+        repetition
+            .ok_or_else(|| Error::Unsupported(format!("repeats are k, k+ or min-max, not {s:?}")))
+    }
+
+    pub fn matches(&self, reps: u32) -> bool {
+        match *self {
+            Repetition::Exactly(k) => reps == k,
+            Repetition::AtLeast(k) => reps >= k,
+            Repetition::Between(min, max) => (min..=max).contains(&reps),
+        }
+    }
+}
+
+// min-max of two numbers, None unless both parse
+fn parse_span(min: &str, max: &str) -> Option<(u32, u32)> {
+    Some((min.parse().ok()?, max.parse().ok()?))
+}
+
+// the flags that ask for a query instead of the puzzle parts
+pub const QUERY_FLAGS: [&str; 8] = [
+    "--reps",
+    "--primitive",
+    "--block",
+    "--base",
+    "--list",
+    "--per-range",
+    "--format",
+    "--overlap",
+];
+
+// which ids count as invalid. an id can be made of different blocks, 111111 is
+// 111 x2, 11 x3 and 1 x6, and it matches if any of them passes the query
+#[derive(Debug, Clone, PartialEq)]
+pub struct Query {
+    pub repetition: Repetition,
+    // only look at the shortest block, so 111111 is just 1 x6
+    pub primitive_only: bool,
+    // digits per block
+    pub block_len: Option<RangeInclusive<u32>>,
+    // base the ids are written in
+    pub base: Base,
+}
+
+impl Query {
+    pub fn new(repetition: Repetition) -> Self {
+        Query {
+            repetition,
+            primitive_only: false,
+            block_len: None,
+            base: Base::DECIMAL,
+        }
+    }
+
+    // --reps <spec> or --part <1|2>, then [--primitive] [--block <n|min-max>] [--base <n>].
+    // None without any of the query flags, --part alone is the puzzle part
+    pub fn from_args(args: &Args) -> Result<Option<Query>, Error> {
+        if !QUERY_FLAGS.iter().any(|flag| args.flag(flag)) {
+            return Ok(None);
+        }
+
+        let repetition = match (args.value("--reps"), args.value("--part")) {
+            (Some(reps), _) => Repetition::parse(reps)?,
+            (None, Some("1")) => Repetition::Exactly(2),
+            (None, Some("2")) => Repetition::AtLeast(2),
+            (None, Some(part)) => {
+                return Err(Error::Unsupported(format!("there is no part {part}")));
+            }
+            (None, None) => {
+                return Err(Error::Unsupported(
+                    "a query needs --reps or --part".to_string(),
+                ));
+            }
+        };
+
+        let mut query = Query::new(repetition);
+        query.primitive_only = args.flag("--primitive");
+        if let Some(block) = args.value("--block") {
+            let (min, max) = block.split_once('-').unwrap_or((block, block));
+            let Some((min, max)) = parse_span(min, max) else {
+                return Err(Error::Unsupported(format!(
+                    "--block needs n or min-max digits, not {block:?}"
+                )));
+            };
+            query.block_len = Some(min..=max);
+        }
+        if let Some(base) = args.value("--base") {
+            let Ok(radix) = base.parse() else {
+                return Err(Error::Unsupported(format!(
+                    "bases are 2 to 36, not {base:?}"
+                )));
+            };
+            query.base = Base::new(radix)?;
+        }
+
+        Ok(Some(query))
+    }
+
+    fn accepts(&self, len: u32, primitive_len: u32) -> bool {
+        self.matching_reps(len, primitive_len).is_some()
+    }
+
+    // most repeats that pass the query for ids of len digits with a primitive block of
+    // primitive_len digits, such an id splits into k equal blocks for every k dividing
+    // len / primitive_len
+    fn matching_reps(&self, len: u32, primitive_len: u32) -> Option<u32> {
+        let max_reps = len / primitive_len;
+
+        divisors(max_reps)
+            .filter(|&reps| reps >= 2 && (!self.primitive_only || reps == max_reps))
+            .filter(|&reps| {
+                self.repetition.matches(reps)
+                    && self
+                        .block_len
+                        .as_ref()
+                        .is_none_or(|b| b.contains(&(len / reps)))
+            })
+            .max()
+    }
+}
+
+// count and sum of matching ids
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Tally {
+    pub count: u128,
+    pub sum: u128,
+}
+
+// ids in range made of a repeated block, grouped by digit count and primitive block
+// (the shortest block that repeats to the id). every id is in exactly one group and
+// the whole group matches the query or not, so no id is counted twice
+pub fn tally(range: RangeInclusive<u64>, query: &Query) -> Tally {
+    let mut tally = Tally::default();
+
+    for (lo, hi, len) in digit_spans(&range, query.base.radix()) {
+        for block_len in divisors(len).filter(|&b| b < len) {
+            if !query.accepts(len, block_len) {
+                continue;
+            }
+
+            let group = primitive_tally(lo, hi, len, block_len, query.base.radix());
+            trace!(
+                "{}-{}: {} digits from a {} digit block, {} ids",
+                lo, hi, len, block_len, group.count
+            );
+
+            tally.count += group.count;
+            tally.sum += group.sum;
+        }
+    }
+
+    debug!("{:?}: {} ids, sum {}", range, tally.count, tally.sum);
+
+    tally
+}
+
+// a matching id, split into the shortest block that passes the query
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidId {
+    pub id: u64,
+    pub block: u64,
+    pub reps: u32,
+}
+
+// every matching id in range, sorted
+pub fn matching_ids(range: RangeInclusive<u64>, query: &Query) -> Vec<InvalidId> {
+    let mut ids = Vec::new();
+
+    for (lo, hi, len) in digit_spans(&range, query.base.radix()) {
+        for block_len in divisors(len).filter(|&b| b < len) {
+            let Some(reps) = query.matching_reps(len, block_len) else {
+                continue;
+            };
+            let reps_multiplier = repeat_multiplier(len, len / reps, query.base.radix());
+
+            let multiplier = repeat_multiplier(len, block_len, query.base.radix());
+            let Some(blocks) = block_range(lo, hi, len, block_len, query.base.radix()) else {
+                continue;
+            };
+
+            for block in blocks {
+                let id = block * multiplier;
+                // blocks that repeat themselves belong to a shorter block's group
+                if primitive_len(id, len, query.base.radix()) == block_len {
+                    ids.push(InvalidId {
+                        id: id as u64,
+                        block: (id / reps_multiplier) as u64,
+                        reps,
+                    });
+                }
+            }
+        }
+    }
+
+    ids.sort_unstable_by_key(|invalid| invalid.id);
+    ids
+}
+
+// matching ids of all ranges in one sorted list, overlapping ranges list an id again
+pub fn invalid_ids(ranges: &[RangeInclusive<u64>], query: &Query) -> Vec<InvalidId> {
+    let mut ids: Vec<InvalidId> = ranges
+        .iter()
+        .flat_map(|range| matching_ids(range.clone(), query))
+        .collect();
+
+    ids.sort_by_key(|invalid| invalid.id);
+    ids
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RangeReport {
+    pub range: RangeInclusive<u64>,
+    pub tally: Tally,
+}
+
+pub fn range_reports(ranges: &[RangeInclusive<u64>], query: &Query) -> Vec<RangeReport> {
+    ranges
+        .iter()
+        .map(|range| RangeReport {
+            range: range.clone(),
+            tally: tally(range.clone(), query),
+        })
+        .collect()
+}
+
+// answers the query about the ranges of the input, [--overlap <set|multiset>] and
+// --list, --per-range or the total in [--format <text|csv|json>]
+pub fn run_query(args: &Args, query: &Query, mut out: &mut dyn Write) -> Result<(), Error> {
+    // ranges and ids are read and written in the query's base
+    let path = args.input_path(Day02::KEY);
+    let file = File::open(&path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+    let ranges = read_ranges(BufReader::new(file), query.base);
+
+    // overlapping ranges count shared ids once with set, once per range with multiset
+    let ranges = match args.value("--overlap") {
+        Some("set") => merge_ranges(ranges),
+        Some("multiset") | None => ranges,
+        Some(overlap) => {
+            return Err(Error::Unsupported(format!(
+                "--overlap is set or multiset, not {overlap:?}"
+            )));
+        }
+    };
+
+    let format = args
+        .value("--format")
+        .map_or(Ok(Format::Text), Format::parse)?;
+
+    if args.flag("--list") {
+        let ids = invalid_ids(&ranges, query);
+        write_ids(&mut out, &ids, format, query.base)?;
+    } else if args.flag("--per-range") {
+        let reports = range_reports(&ranges, query);
+        write_range_reports(&mut out, &reports, format, query.base)?;
+    } else {
+        let (count, sum) = ranges
+            .into_iter()
+            .map(|range| tally(range, query))
+            .fold((0, 0), |(count, sum), t| (count + t.count, sum + t.sum));
+        writeln!(
+            out,
+            "Query: {count} ids, sum {}",
+            format_in_base(sum, query.base)
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Csv,
+    Json,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Result<Format, Error> {
+        match s {
+            "text" => Ok(Format::Text),
+            "csv" => Ok(Format::Csv),
+            "json" => Ok(Format::Json),
+            _ => Err(Error::Unsupported(format!(
+                "formats are text, csv and json, not {s:?}"
+            ))),
+        }
+    }
+}
+
+// numbers as json, quoted outside base 10 where they have letters in them
+fn json_number(n: u128, base: Base) -> String {
+    if base == Base::DECIMAL {
+        n.to_string()
+    } else {
+        format!("\"{}\"", format_in_base(n, base))
+    }
+}
+
+pub fn write_ids(
+    out: &mut impl Write,
+    ids: &[InvalidId],
+    format: Format,
+    base: Base,
+) -> io::Result<()> {
+    let fmt = |n: u64| format_in_base(n as u128, base);
+
+    match format {
+        Format::Text => {
+            for invalid in ids {
+                writeln!(out, "{}", fmt(invalid.id))?;
+            }
+        }
+        Format::Csv => {
+            writeln!(out, "id,block,reps")?;
+            for invalid in ids {
+                writeln!(
+                    out,
+                    "{},{},{}",
+                    fmt(invalid.id),
+                    fmt(invalid.block),
+                    invalid.reps
+                )?;
+            }
+        }
+        Format::Json => {
+            let rows: Vec<String> = ids
+                .iter()
+                .map(|invalid| {
+                    format!(
+                        "{{\"id\":{},\"block\":{},\"reps\":{}}}",
+                        json_number(invalid.id as u128, base),
+                        json_number(invalid.block as u128, base),
+                        invalid.reps
+                    )
+                })
+                .collect();
+            writeln!(out, "[{}]", rows.join(","))?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn write_range_reports(
+    out: &mut impl Write,
+    reports: &[RangeReport],
+    format: Format,
+    base: Base,
+) -> io::Result<()> {
+    let fmt = |n: u128| format_in_base(n, base);
+
+    match format {
+        Format::Text => {
+            for report in reports {
+                writeln!(
+                    out,
+                    "{}-{}: {} ids, sum {}",
+                    fmt(*report.range.start() as u128),
+                    fmt(*report.range.end() as u128),
+                    report.tally.count,
+                    fmt(report.tally.sum)
+                )?;
+            }
+        }
+        Format::Csv => {
+            writeln!(out, "start,end,count,sum")?;
+            for report in reports {
+                writeln!(
+                    out,
+                    "{},{},{},{}",
+                    fmt(*report.range.start() as u128),
+                    fmt(*report.range.end() as u128),
+                    report.tally.count,
+                    fmt(report.tally.sum)
+                )?;
+            }
+        }
+        Format::Json => {
+            let rows: Vec<String> = reports
+                .iter()
+                .map(|report| {
+                    format!(
+                        "{{\"start\":{},\"end\":{},\"count\":{},\"sum\":{}}}",
+                        json_number(*report.range.start() as u128, base),
+                        json_number(*report.range.end() as u128, base),
+                        report.tally.count,
+                        json_number(report.tally.sum, base)
+                    )
+                })
+                .collect();
+            writeln!(out, "[{}]", rows.join(","))?;
+        }
+    }
+
+    Ok(())
+}
+
+// splits range into parts with the same number of digits, as (lo, hi, digits)
+fn digit_spans(range: &RangeInclusive<u64>, base: u32) -> impl Iterator<Item = (u128, u128, u32)> {
+    let (start, end) = (*range.start() as u128, *range.end() as u128);
+    let base = base as u128;
+
+    // most digits a u64 can have in this base
+    let max_digits = u64::MAX.ilog(base as u64) + 1;
+
+    (2..=max_digits).filter_map(move |len| {
+        let lo = start.max(base.pow(len - 1));
+        let hi = end.min(base.pow(len) - 1);
+        (lo <= hi).then_some((lo, hi, len))
+    })
+}
+
+// id = block * 10..010..01 (in the base), one 1 per repeat
+fn repeat_multiplier(len: u32, block_len: u32, base: u32) -> u128 {
+    let base = base as u128;
+
+    (base.pow(len) - 1) / (base.pow(block_len) - 1)
+}
+
+// blocks of block_len digits that repeat to an id of len digits in lo..=hi
+fn block_range(
+    lo: u128,
+    hi: u128,
+    len: u32,
+    block_len: u32,
+    base: u32,
+) -> Option<RangeInclusive<u128>> {
+    let multiplier = repeat_multiplier(len, block_len, base);
+    let base = base as u128;
+
+    // blocks with a leading zero would make a shorter id
+    let first = base.pow(block_len - 1).max(lo.div_ceil(multiplier));
+    let last = (base.pow(block_len) - 1).min(hi / multiplier);
+
+    (first <= last).then_some(first..=last)
+}
+
+// digits in the shortest block that repeats to id
+fn primitive_len(id: u128, len: u32, base: u32) -> u32 {
+    divisors(len)
+        .find(|&d| {
+            let multiplier = repeat_multiplier(len, d, base);
+            id.is_multiple_of(multiplier) && id / multiplier < (base as u128).pow(d)
+        })
+        .unwrap_or(len)
+}
+
+// n written out in the base, lowercase letters for digits past 9
+pub fn format_in_base(n: u128, base: Base) -> String {
+    const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+    let base = base.radix() as u128;
+    let mut n = n;
+    let mut digits = Vec::new();
+
+    loop {
+        digits.push(DIGITS[(n % base) as usize] as char);
+        n /= base;
+        if n == 0 {
+            break;
+        }
+    }
+
+    digits.iter().rev().collect()
+}
+
+// ids of len digits in lo..=hi whose primitive block has exactly block_len digits,
+// by mobius inversion over the ids repeating any block whose length divides block_len
+fn primitive_tally(lo: u128, hi: u128, len: u32, block_len: u32, base: u32) -> Tally {
+    let mut count = 0i128;
+    let mut sum = 0i128;
+
+    for d in divisors(block_len) {
+        let sign = mobius(block_len / d) as i128;
+        if sign != 0 {
+            let periodic = periodic_tally(lo, hi, len, d, base);
+            count += sign * periodic.count as i128;
+            sum += sign * periodic.sum as i128;
+        }
+    }
+
+    Tally {
+        count: count as u128,
+        sum: sum as u128,
+    }
+}
+
+// ids of len digits in lo..=hi made of any block of block_len digits repeated
+fn periodic_tally(lo: u128, hi: u128, len: u32, block_len: u32, base: u32) -> Tally {
+    let Some(blocks) = block_range(lo, hi, len, block_len, base) else {
+        return Tally::default();
+    };
+
+    let (first, last) = (*blocks.start(), *blocks.end());
+    let count = last - first + 1;
+    Tally {
+        count,
+        // arithmetic series of blocks
+        sum: repeat_multiplier(len, block_len, base) * ((first + last) * count / 2),
+    }
+}
+
+fn divisors(n: u32) -> impl Iterator<Item = u32> {
+    (1..=n).filter(move |&d| n.is_multiple_of(d))
+}
+
+// 0 if n has a squared prime factor, otherwise -1 or 1 for an odd or even number of primes
+fn mobius(n: u32) -> i32 {
+    let mut n = n;
+    let mut result = 1;
+    let mut p = 2;
+
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            n /= p;
+            if n.is_multiple_of(p) {
+                return 0;
+            }
+            result = -result;
+        }
+        p += 1;
+    }
+
+    if n > 1 { -result } else { result }
+}
+
+#[test]
+fn test_p1() {
+    let data = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 1227775554)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
+
+    assert_eq!(part2(data.as_bytes()).unwrap(), 4174379265)
+}
+
+#[test]
+fn test_matches_scan() {
+    // a brute-force scan of every id, repeating its digits by string, against the grouped
+    // tally and listing
+    let scan_matches = |n: u64, query: &Query| {
+        let num = format_in_base(n as u128, query.base);
+        let len = num.len();
+        let reps: Vec<usize> = (2..=len)
+            .filter(|&k| len.is_multiple_of(k) && num[..len / k].repeat(k) == num)
+            .collect();
+        let max_reps = reps.iter().copied().max();
+
+        reps.iter().any(|&k| {
+            (!query.primitive_only || Some(k) == max_reps)
+                && query.repetition.matches(k as u32)
+                && query
+                    .block_len
+                    .as_ref()
+                    .is_none_or(|b| b.contains(&((len / k) as u32)))
+        })
+    };
+
+    let mut primitive = Query::new(Repetition::Exactly(2));
+    primitive.primitive_only = true;
+    let mut short_blocks = Query::new(Repetition::Between(2, 3));
+    short_blocks.block_len = Some(1..=2);
+    let mut binary = Query::new(Repetition::AtLeast(2));
+    binary.base = Base::new(2).unwrap();
+    let mut hex = Query::new(Repetition::Exactly(2));
+    hex.base = Base::new(16).unwrap();
+    let mut base36 = Query::new(Repetition::AtLeast(3));
+    base36.base = Base::new(36).unwrap();
+
+    let queries = [
+        Query::new(Repetition::Exactly(2)),
+        Query::new(Repetition::AtLeast(2)),
+        Query::new(Repetition::Exactly(3)),
+        primitive,
+        short_blocks,
+        binary,
+        hex,
+        base36,
+    ];
+
+    for query in queries {
+        // every id up to 100_000, so all digit lengths up to 5 in base 10, then a window
+        // across every later change of the number of digits
+        let base = query.base.radix() as u64;
+        let boundaries = (1..)
+            .map(|len| base.pow(len))
+            .skip_while(|&b| b <= 100_000)
+            .take_while(|&b| b <= 10_000_000);
+        let ranges = std::iter::once(1..=100_000).chain(boundaries.map(|b| b - 500..=b + 500));
+
+        for range in ranges {
+            let scanned: Vec<u64> = range.clone().filter(|&n| scan_matches(n, &query)).collect();
+            let tally = tally(range.clone(), &query);
+
+            let listed: Vec<u64> = matching_ids(range.clone(), &query)
+                .iter()
+                .map(|invalid| invalid.id)
+                .collect();
+
+            assert_eq!(listed, scanned, "{range:?}");
+            assert_eq!(tally.count, scanned.len() as u128);
+            assert_eq!(tally.sum, scanned.iter().map(|&n| n as u128).sum::<u128>());
+        }
+    }
+}
+
+#[test]
+fn test_full_u64_range() {
+    // every id of an even number of digits whose halves match, up to u64::MAX
+    let tally = tally(1..=u64::MAX, &Query::new(Repetition::Exactly(2)));
+    let ten_digit_blocks = u64::MAX as u128 / (10u128.pow(10) + 1) - 10u128.pow(9) + 1;
+
+    assert_eq!(tally.count, 999_999_999 + ten_digit_blocks)
+}
+
+#[test]
+fn test_base() {
+    let ranges = read_ranges("a-ff,zz0-zzz".as_bytes(), Base::new(36).unwrap());
+
+    assert_eq!(ranges, [10..=555, 46620..=46655]);
+    assert!(Base::new(1).is_err() && Base::new(37).is_err());
+    assert_eq!(format_in_base(46655, Base::new(36).unwrap()), "zzz");
+    assert_eq!(format_in_base(0b1010, Base::new(2).unwrap()), "1010");
+
+    // 0x11, 0x22 .. 0xff
+    let mut hex = Query::new(Repetition::Exactly(2));
+    hex.base = Base::new(16).unwrap();
+
+    assert_eq!(tally(0..=0xff, &hex).count, 15);
+    assert_eq!(tally(0..=0xff, &hex).sum, 17 * (1..=15).sum::<u128>())
+}
+
+#[test]
+fn test_output_modes() {
+    let ranges = read_ranges("1100-1112,95-115".as_bytes(), Base::DECIMAL);
+    let query = Query::new(Repetition::AtLeast(2));
+
+    let ids = invalid_ids(&ranges, &query);
+    assert_eq!(
+        ids,
+        [
+            InvalidId {
+                id: 99,
+                block: 9,
+                reps: 2
+            },
+            InvalidId {
+                id: 111,
+                block: 1,
+                reps: 3
+            },
+            InvalidId {
+                id: 1111,
+                block: 1,
+                reps: 4
+            }
+        ]
+    );
+
+    let mut csv = Vec::new();
+    write_ids(&mut csv, &ids, Format::Csv, Base::DECIMAL).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "id,block,reps\n99,9,2\n111,1,3\n1111,1,4\n"
+    );
+
+    let mut json = Vec::new();
+    let reports = range_reports(&ranges, &query);
+    write_range_reports(&mut json, &reports, Format::Json, Base::DECIMAL).unwrap();
+    assert_eq!(
+        String::from_utf8(json).unwrap(),
+        r#"[{"start":1100,"end":1112,"count":1,"sum":1111},{"start":95,"end":115,"count":2,"sum":210}]"#
+            .to_owned()
+            + "\n"
+    );
+
+    // exactly twice splits 1111 into 11 twice
+    let ids = invalid_ids(&ranges, &Query::new(Repetition::Exactly(2)));
+    assert_eq!(ids[1].block, 11);
+}
+
+#[test]
+fn test_overlap() {
+    let ranges = read_ranges("95-115,11-22,15-33,11-22".as_bytes(), Base::DECIMAL);
+    let merged = merge_ranges(ranges.clone());
+    let query = Query::new(Repetition::AtLeast(2));
+
+    assert_eq!(merged, [11..=33, 95..=115]);
+
+    // 11 and 22 twice each in the duplicate range and 22 once more in the overlap
+    let multiset: u128 = ranges.iter().map(|r| tally(r.clone(), &query).sum).sum();
+    let set: u128 = merged.iter().map(|r| tally(r.clone(), &query).sum).sum();
+
+    assert_eq!(multiset, 11 * 2 + 22 * 3 + 33 + 99 + 111);
+    assert_eq!(set, 11 + 22 + 33 + 99 + 111)
+}
+
+#[test]
+fn test_range_reader() {
+    let data = "11-22, 95-115,\n998-10x12 ,,1188511880-1188511890\n";
+
+    let ranges: Vec<_> = RangeReader::new(data.as_bytes(), Base::DECIMAL).collect();
+
+    assert_eq!(
+        ranges,
+        [
+            Ok(11..=22),
+            Ok(95..=115),
+            Err(RangeError::Bad {
+                offset: 15,
+                entry: "998-10x12".to_string()
+            }),
+            Ok(1188511880..=1188511890)
+        ]
+    );
+
+    // trailing newline after the last range
+    assert_eq!(part1("11-22,95-115\n".as_bytes()).unwrap(), 11 + 22 + 99);
+    assert!(part2(data.as_bytes()).is_err())
+}
+
+#[test]
+fn test_range_reader_io() {
+    // the first ranges, then the read fails
+    struct Failing<'a>(&'a [u8]);
+
+    impl io::Read for Failing<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0.is_empty() {
+                return Err(io::Error::other("disk gone"));
+            }
+            self.0.read(buf)
+        }
+    }
+
+    let input = io::BufReader::new(Failing(b"11-22,95-115,99"));
+    let ranges: Vec<_> = RangeReader::new(input, Base::DECIMAL).collect();
+
+    assert_eq!(
+        ranges,
+        [
+            Ok(11..=22),
+            Ok(95..=115),
+            Err(RangeError::Io {
+                offset: 13,
+                message: "disk gone".to_string()
+            })
+        ]
+    );
+
+    let input = io::BufReader::new(Failing(b"11-22"));
+    assert!(matches!(part1(input), Err(Error::Io(_))))
+}
+
+#[test]
+fn test_query_args() {
+    let query =
+        |args: &[&str]| Query::from_args(&Args::new(args.iter().map(|a| a.to_string()).collect()));
+
+    let mut expected = Query::new(Repetition::Between(2, 4));
+    expected.block_len = Some(3..=3);
+    expected.base = Base::new(16).unwrap();
+    assert_eq!(
+        query(&["--reps", "2-4", "--block", "3", "--base", "16"]),
+        Ok(Some(expected))
+    );
+    assert_eq!(
+        query(&["--part", "2", "--list"]),
+        Ok(Some(Query::new(Repetition::AtLeast(2))))
+    );
+    // the puzzle part
+    assert_eq!(query(&["--part", "2"]), Ok(None));
+
+    for bad in [
+        &["--part", "3", "--list"][..],
+        &["--list"],
+        &["--reps", "x"],
+        &["--reps", "2-"],
+        &["--reps", "2", "--block", "1-y"],
+        &["--reps", "2", "--base", "37"],
+    ] {
+        assert!(query(bad).is_err(), "{bad:?}");
+    }
+    assert_eq!(Format::parse("csv"), Ok(Format::Csv));
+    assert!(Format::parse("xml").is_err())
+}
+
+#[test]
+fn test_hex_query() {
+    // ranges with letters in them, read and answered in base 16 end to end
+    let path = std::env::temp_dir().join(format!("aoc2025-day02-hex-{}.txt", std::process::id()));
+    std::fs::write(&path, "a-ff,1010-1020\n").unwrap();
+    let run = |args: &[&str]| {
+        let args: Vec<String> = ["--input", path.to_str().unwrap()]
+            .iter()
+            .chain(args)
+            .map(|a| a.to_string())
+            .collect();
+        let args = Args::new(args);
+
+        // the way the day's binary and aoc run pick the query
+        let mut out = Vec::new();
+        let day = aoc::Day::<Day02>::new();
+        let result = aoc::Puzzle::run_mode(&day, &args, &mut out).expect("a query");
+        result.map(|()| String::from_utf8(out).unwrap())
+    };
+
+    // 11 to ff, then 1010
+    let total = run(&["--base", "16", "--reps", "2"]);
+    let list = run(&["--base", "16", "--reps", "2", "--list", "--format", "csv"]);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(total.unwrap(), "Query: 16 ids, sum 1808\n");
+    let list = list.unwrap();
+    assert!(list.starts_with("id,block,reps\n11,1,2\n"));
+    assert!(list.ends_with("ff,f,2\n1010,10,2\n"))
+}
@@ -0,0 +1,5 @@
+use aoc2025_day02::Day02;
+
+fn main() {
+    aoc::main::<Day02>();
+}
@@ -0,0 +1,560 @@
+use std::{
+    fmt,
+    fs::File,
+    io::{BufRead, BufReader},
+    ops::AddAssign,
+};
+
+use aoc::{Answer, Args, Error, Key, Mode, Solution, warn};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const KEY: Key = Key::new(2025, 3);
+
+    // banks are read one line at a time while solving
+    type Input = String;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "monotonic stack, 2 digits per bank",
+        "monotonic stack, 12 digits per bank",
+    ];
+
+    fn parse(data: &str) -> Result<String, Error> {
+        Ok(data.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer, Error> {
+        Ok(part1(input.as_bytes())?.to_string())
+    }
+
+    fn part2(input: &String) -> Result<Answer, Error> {
+        Ok(part2(input.as_bytes())?.to_string())
+    }
+
+    fn stream(part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        match part {
+            1 => Some(part1(input).map(|total| total.to_string())),
+            2 => Some(part2(input).map(|total| total.to_string())),
+            _ => None,
+        }
+    }
+
+    fn modes() -> Vec<Mode> {
+        vec![Mode {
+            flags: &[
+                "--batteries",
+                "--min",
+                "--no-adjacent",
+                "--window",
+                "--leading",
+            ],
+            values: &["--batteries", "--window", "--leading"],
+            usage: "--batteries <k> [--min] [--no-adjacent] [--window <m/w>] [--leading <d>]  \
+                    total joltage of another selection",
+            run: |args, out| {
+                let Some(selection) = Selection::from_args(args)? else {
+                    return Err(Error::Unsupported(
+                        "a selection needs --batteries".to_string(),
+                    ));
+                };
+
+                let path = args.input_path(Day03::KEY);
+                let file =
+                    File::open(&path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+                let total = total_joltage(BufReader::new(file), &selection)?;
+                writeln!(out, "{} batteries: {total}", selection.k)?;
+                Ok(())
+            },
+        }]
+    }
+}
+
+pub fn part1(input: impl BufRead) -> Result<Joltage, Error> {
+    sum_banks(input, &Selection::new(2), false)
+}
+
+pub fn part2(input: impl BufRead) -> Result<Joltage, Error> {
+    sum_banks(input, &Selection::new(12), false)
+}
+
+// sum of the selected joltage of every bank, banks where the selection can't be met are
+// reported on stderr and skipped
+pub fn total_joltage(input: impl BufRead, selection: &Selection) -> Result<Joltage, Error> {
+    sum_banks(input, selection, true)
+}
+
+// blank lines are skipped, a bank the selection can't be met in is skipped as well or an
+// error
+fn sum_banks(
+    input: impl BufRead,
+    selection: &Selection,
+    skip_unmet: bool,
+) -> Result<Joltage, Error> {
+    let mut total = Joltage::default();
+    for (i, line) in input.lines().enumerate() {
+        let line = line?;
+        let Some(bank) = line
+            .trim()
+            .chars()
+            .map(|c| c.to_digit(10))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Err(Error::at_line(i + 1, format!("bad bank {line:?}")));
+        };
+        if bank.is_empty() {
+            continue;
+        }
+
+        match select(&bank, selection) {
+            Some((jolt, _)) => total += &jolt,
+            None if skip_unmet => warn!("line {}: skipping bank {line:?}", i + 1),
+            None => {
+                return Err(Error::at_line(
+                    i + 1,
+                    format!("bank {line:?} has fewer than {} batteries", selection.k),
+                ));
+            }
+        }
+    }
+
+    Ok(total)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Goal {
+    Max,
+    Min,
+}
+
+impl Goal {
+    // a is strictly better than b
+    fn prefers(self, a: u32, b: u32) -> bool {
+        match self {
+            Goal::Max => a > b,
+            Goal::Min => a < b,
+        }
+    }
+}
+
+// which k batteries to turn on in a bank
+#[derive(Debug, Clone)]
+pub struct Selection {
+    pub goal: Goal,
+    pub k: usize,
+    // (m, w): at most m picked batteries in any w consecutive ones
+    pub windows: Vec<(usize, usize)>,
+    pub leading: Option<u32>,
+}
+
+impl Selection {
+    // largest k battery joltage, the puzzle rule
+    pub fn new(k: usize) -> Self {
+        Selection {
+            goal: Goal::Max,
+            k,
+            windows: Vec::new(),
+            leading: None,
+        }
+    }
+
+    // --batteries <k>, then [--min] [--no-adjacent] [--window <m/w>] [--leading <d>], None
+    // without --batteries
+    pub fn from_args(args: &Args) -> Result<Option<Selection>, Error> {
+        let Some(k) = args.value("--batteries") else {
+            return Ok(None);
+        };
+        let Ok(k) = k.parse() else {
+            return Err(Error::Unsupported(format!(
+                "--batteries needs a number, not {k:?}"
+            )));
+        };
+
+        let mut selection = Selection::new(k);
+        if args.flag("--min") {
+            selection.goal = Goal::Min;
+        }
+        if args.flag("--no-adjacent") {
+            selection.windows.push((1, 2));
+        }
+        if let Some(window) = args.value("--window") {
+            // 0 picks or a window of 0 batteries would quietly limit nothing
+            let Some((m, w)) = window
+                .split_once('/')
+                .and_then(|(m, w)| Some((m.parse().ok()?, w.parse().ok()?)))
+                .filter(|&(m, w)| m > 0 && w > 0)
+            else {
+                return Err(Error::Unsupported(format!(
+                    "--window needs m/w of at least 1/1, not {window:?}"
+                )));
+            };
+            selection.windows.push((m, w));
+        }
+        if let Some(leading) = args.value("--leading") {
+            let Some(digit) = leading.parse().ok().filter(|&d| d <= 9) else {
+                return Err(Error::Unsupported(format!(
+                    "--leading needs a digit, not {leading:?}"
+                )));
+            };
+            selection.leading = Some(digit);
+        }
+
+        Ok(Some(selection))
+    }
+
+    // first index the next battery may come from after the picked ones
+    fn next_allowed(&self, picked: &[usize]) -> usize {
+        let mut from = picked.last().map_or(0, |&p| p + 1);
+        for &(m, w) in &self.windows {
+            // m picks back plus w is the first spot outside its window
+            if m > 0 && picked.len() >= m {
+                from = from.max(picked[picked.len() - m] + w);
+            }
+        }
+        from
+    }
+
+    // whether left more batteries still fit after the picked ones, taking each as early as allowed
+    fn fits(&self, picked: &mut Vec<usize>, left: usize, len: usize) -> bool {
+        let start = picked.len();
+        let mut fits = true;
+        for _ in 0..left {
+            let next = self.next_allowed(picked);
+            if next >= len {
+                fits = false;
+                break;
+            }
+            picked.push(next);
+        }
+        picked.truncate(start);
+        fits
+    }
+}
+
+// best joltage of the bank under the selection with the indices picked, None when the
+// selection can't be met
+pub fn select(bank: &[u32], selection: &Selection) -> Option<(Joltage, Vec<usize>)> {
+    let k = selection.k;
+    if bank.len() < k || (k == 0 && selection.leading.is_some()) {
+        return None;
+    }
+
+    let picked = if !selection.windows.is_empty() {
+        constrained_subsequence(bank, selection)?
+    } else if let Some(lead) = selection.leading {
+        // the first fitting lead leaves the most room for the rest
+        let first = bank[..=bank.len() - k].iter().position(|&d| d == lead)?;
+        let rest = stack_subsequence(&bank[first + 1..], k - 1, selection.goal);
+        [first]
+            .into_iter()
+            .chain(rest.into_iter().map(|i| first + 1 + i))
+            .collect()
+    } else {
+        stack_subsequence(bank, k, selection.goal)
+    };
+
+    let value = Joltage::from_digits(picked.iter().map(|&i| bank[i]));
+    Some((value, picked))
+}
+
+// largest number made of k digits of the bank kept in order, with the indices of the picked digits
+pub fn max_subsequence(bank: &[u32], k: usize) -> (Joltage, Vec<usize>) {
+    assert!(
+        k <= bank.len(),
+        "can't pick {k} of {} batteries",
+        bank.len()
+    );
+
+    let picked = stack_subsequence(bank, k, Goal::Max);
+    let value = Joltage::from_digits(picked.iter().map(|&i| bank[i]));
+    (value, picked)
+}
+
+// a digit pushes worse ones before it off the stack as long as enough digits are left to fill k
+pub fn stack_subsequence(bank: &[u32], k: usize, goal: Goal) -> Vec<usize> {
+    let mut stack: Vec<usize> = Vec::with_capacity(k);
+    for (i, &digit) in bank.iter().enumerate() {
+        let left = bank.len() - i;
+        // strict compare so the first of equal digits is kept
+        while let Some(&top) = stack.last()
+            && goal.prefers(digit, bank[top])
+            && stack.len() - 1 + left >= k
+        {
+            stack.pop();
+        }
+        if stack.len() < k {
+            stack.push(i);
+        }
+    }
+
+    stack
+}
+
+// the stack drops picks it can't refill once windows limit where the next battery may go,
+// so pick digit by digit instead: the best digit that still lets the remaining picks fit.
+// the earliest of equal digits never leaves less room, O(n * k^2)
+fn constrained_subsequence(bank: &[u32], selection: &Selection) -> Option<Vec<usize>> {
+    let mut picked: Vec<usize> = Vec::with_capacity(selection.k);
+    for left in (0..selection.k).rev() {
+        let mut best: Option<usize> = None;
+        for i in selection.next_allowed(&picked)..bank.len() {
+            if picked.is_empty() && selection.leading.is_some_and(|d| d != bank[i]) {
+                continue;
+            }
+            if best.is_some_and(|b| !selection.goal.prefers(bank[i], bank[b])) {
+                continue;
+            }
+
+            picked.push(i);
+            if selection.fits(&mut picked, left, bank.len()) {
+                best = Some(i);
+            }
+            picked.pop();
+        }
+        picked.push(best?);
+    }
+
+    Some(picked)
+}
+
+// decimal number of any length, joltages of long picks and their sums don't fit a u64
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Joltage {
+    // least significant digit first, no trailing zeros so zero is empty
+    digits: Vec<u8>,
+}
+
+impl Joltage {
+    // most significant digit first, as read from the bank
+    pub fn from_digits(digits: impl DoubleEndedIterator<Item = u32>) -> Self {
+        let mut joltage = Joltage {
+            digits: digits.rev().map(|d| d as u8).collect(),
+        };
+        joltage.trim();
+        joltage
+    }
+
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+}
+
+impl AddAssign<&Joltage> for Joltage {
+    fn add_assign(&mut self, other: &Joltage) {
+        if self.digits.len() < other.digits.len() {
+            self.digits.resize(other.digits.len(), 0);
+        }
+
+        let mut carry = 0;
+        for i in 0..self.digits.len() {
+            let sum = self.digits[i] + other.digits.get(i).copied().unwrap_or(0) + carry;
+            self.digits[i] = sum % 10;
+            carry = sum / 10;
+            if carry == 0 && i >= other.digits.len() {
+                break;
+            }
+        }
+        if carry > 0 {
+            self.digits.push(carry);
+        }
+    }
+}
+
+impl fmt::Display for Joltage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        for d in self.digits.iter().rev() {
+            write!(f, "{d}")?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn test_p1() {
+    let data = r"987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    assert_eq!(part1(data.as_bytes()).unwrap().to_string(), "357")
+}
+
+#[test]
+fn test_p1_1() {
+    let data = r"9891";
+
+    assert_eq!(part1(data.as_bytes()).unwrap().to_string(), "99")
+}
+
+#[test]
+fn test_p2() {
+    let data = r"987654321111111
+811111111111119
+234234234234278
+818181911112111";
+
+    assert_eq!(part2(data.as_bytes()).unwrap().to_string(), "3121910778619")
+}
+
+#[test]
+fn test_p1_empty_line() {
+    let data = "9891\n\n";
+
+    assert_eq!(part1(data.as_bytes()).unwrap().to_string(), "99");
+    assert_eq!(
+        part1("98x1\n".as_bytes()).unwrap_err().to_string(),
+        "bad input, line 1: bad bank \"98x1\""
+    );
+    assert_eq!(
+        part2("987654321111111\n123\n".as_bytes())
+            .unwrap_err()
+            .to_string(),
+        "bad input, line 2: bank \"123\" has fewer than 12 batteries"
+    );
+    // selections skip the banks they can't be met in
+    let total = total_joltage("987654321111111\n123\n".as_bytes(), &Selection::new(12));
+    assert_eq!(total.unwrap().to_string(), "987654321111")
+}
+
+#[test]
+fn test_max_subsequence() {
+    let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+
+    let (value, picked) = max_subsequence(&bank, 2);
+    assert_eq!((value.to_string(), picked), ("92".to_string(), vec![6, 11]));
+    let (value, picked) = max_subsequence(&bank, 4);
+    assert_eq!(
+        (value.to_string(), picked),
+        ("9211".to_string(), vec![6, 11, 12, 13])
+    );
+    assert_eq!(max_subsequence(&bank, 15).0.to_string(), "818181911112111");
+    let (value, picked) = max_subsequence(&[9, 9, 9], 2);
+    assert_eq!((value.to_string(), picked), ("99".to_string(), vec![0, 1]))
+}
+
+#[test]
+fn test_select_max() {
+    let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+    let max = |bank: &[u32], k| {
+        let (value, picked) = select(bank, &Selection::new(k)).unwrap();
+        (value.to_string(), picked)
+    };
+
+    assert_eq!(max(&bank, 2), ("92".to_string(), vec![6, 11]));
+    assert_eq!(max(&bank, 4), ("9211".to_string(), vec![6, 11, 12, 13]));
+    assert_eq!(max(&bank, 15).0, "818181911112111");
+    assert_eq!(max(&[9, 9, 9], 2).0, "99")
+}
+
+#[test]
+fn test_long_joltage() {
+    // two banks of 50 nines, past what a u64 holds
+    let data = format!("{0}1\n{0}\n", "9".repeat(50));
+
+    let total = total_joltage(data.as_bytes(), &Selection::new(50)).unwrap();
+
+    assert_eq!(total.to_string(), format!("1{}8", "9".repeat(49)));
+    assert_eq!(Joltage::default().to_string(), "0")
+}
+
+#[test]
+fn test_selection() {
+    let bank = [8, 1, 8, 1, 8, 1, 9, 1, 1, 1, 1, 2, 1, 1, 1];
+    let picked = |selection: &Selection| select(&bank, selection).map(|(_, picked)| picked);
+
+    let mut min = Selection::new(3);
+    min.goal = Goal::Min;
+    assert_eq!(picked(&min), Some(vec![1, 3, 5]));
+
+    // greedy takes 92, but 9 and 2 are too close for 1 per 6
+    let mut spaced = Selection::new(2);
+    spaced.windows.push((1, 6));
+    assert_eq!(picked(&spaced), Some(vec![6, 12]));
+
+    let mut lead = Selection::new(3);
+    lead.leading = Some(2);
+    assert_eq!(picked(&lead), Some(vec![11, 12, 13]));
+    lead.leading = Some(7);
+    assert_eq!(picked(&lead), None);
+
+    let mut crowded = Selection::new(8);
+    crowded.windows.push((1, 2));
+    assert_eq!(picked(&crowded), Some(vec![0, 2, 4, 6, 8, 10, 12, 14]));
+    crowded.k = 9;
+    assert_eq!(picked(&crowded), None)
+}
+
+#[test]
+fn test_selection_matches_brute_force() {
+    let bank = [3, 9, 1, 9, 4, 7, 2, 7, 9, 5, 1, 6];
+    let goals = [Goal::Max, Goal::Min];
+    let rules = [vec![], vec![(1, 2)], vec![(2, 4)], vec![(1, 2), (2, 5)]];
+
+    for k in 1..=6 {
+        for goal in goals {
+            for windows in &rules {
+                for leading in [None, Some(9), Some(7)] {
+                    let selection = Selection {
+                        goal,
+                        k,
+                        windows: windows.clone(),
+                        leading,
+                    };
+
+                    // every subset of k batteries that keeps the rules, compared as digit strings
+                    let best = (0u32..1 << bank.len())
+                        .filter(|mask| mask.count_ones() as usize == k)
+                        .map(|mask| (0..bank.len()).filter(|i| mask >> i & 1 == 1).collect())
+                        .filter(|picked: &Vec<usize>| {
+                            leading.is_none_or(|d| bank[picked[0]] == d)
+                                && windows
+                                    .iter()
+                                    .all(|&(m, w)| picked.windows(m + 1).all(|p| p[m] - p[0] >= w))
+                        })
+                        .map(|picked| picked.iter().map(|&i| bank[i]).collect::<Vec<_>>())
+                        .reduce(|a, b| match goal {
+                            Goal::Max => a.max(b),
+                            Goal::Min => a.min(b),
+                        });
+
+                    let found = select(&bank, &selection)
+                        .map(|(_, picked)| picked.iter().map(|&i| bank[i]).collect());
+                    assert_eq!(found, best, "{selection:?}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_selection_args() {
+    let selection = |args: &[&str]| {
+        Selection::from_args(&Args::new(args.iter().map(|a| a.to_string()).collect()))
+    };
+
+    let spaced = selection(&["--batteries", "3", "--no-adjacent", "--window", "2/5"]);
+    assert_eq!(spaced.unwrap().unwrap().windows, [(1, 2), (2, 5)]);
+    assert_eq!(
+        selection(&["--batteries", "3", "--leading", "7"])
+            .unwrap()
+            .unwrap()
+            .leading,
+        Some(7)
+    );
+    assert!(selection(&["--min"]).unwrap().is_none());
+
+    for bad in [
+        &["--batteries", "3", "--window", "0/5"][..],
+        &["--batteries", "3", "--window", "2/0"],
+        &["--batteries", "3", "--window", "2"],
+        &["--batteries", "x"],
+        &["--batteries", "3", "--leading", "12"],
+    ] {
+        assert!(selection(bad).is_err(), "{bad:?}");
+    }
+}
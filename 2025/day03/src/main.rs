@@ -0,0 +1,5 @@
+use aoc2025_day03::Day03;
+
+fn main() {
+    aoc::main::<Day03>();
+}
@@ -0,0 +1,263 @@
+use std::collections::{HashMap, VecDeque};
+
+use aoc::{Answer, Error, Key, Render, Solution, Variant, export::Grid};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const KEY: Key = Key::new(2025, 4);
+
+    type Input = Floor;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "neighbour count per roll",
+        "rescan and remove accessible rolls until none are left",
+    ];
+
+    fn parse(data: &str) -> Result<Floor, Error> {
+        parse_rolls(data)
+    }
+
+    fn part1(floor: &Floor) -> Result<Answer, Error> {
+        Ok(part1(&floor.rolls).to_string())
+    }
+
+    fn part2(floor: &Floor) -> Result<Answer, Error> {
+        Ok(part2(&floor.rolls).to_string())
+    }
+
+    fn variants() -> Vec<Variant<Floor>> {
+        vec![
+            Variant {
+                name: "rescan",
+                part: 2,
+                solve: |floor| Ok(part2(&floor.rolls).to_string()),
+            },
+            Variant {
+                name: "queue",
+                part: 2,
+                solve: |floor| Ok(part2_queue(&floor.rolls).to_string()),
+            },
+        ]
+    }
+
+    fn render(floor: &Floor) -> Result<Render, Error> {
+        Ok(Render::Grid(render(floor)))
+    }
+}
+
+// the rolls on the floor plus its size, rolls are keyed by (x, y)
+pub struct Floor {
+    pub rolls: HashMap<(i16, i16), char>,
+    pub width: usize,
+    pub height: usize,
+}
+
+pub fn parse_rolls(data: &str) -> Result<Floor, Error> {
+    let mut coords: HashMap<(i16, i16), char> = HashMap::new();
+    let mut width = 0;
+    let mut height = 0;
+    for (row_idx, line) in data.lines().enumerate() {
+        // coordinates and their neighbours have to fit an i16
+        if row_idx >= i16::MAX as usize || line.len() >= i16::MAX as usize {
+            return Err(Error::at_line(row_idx + 1, "floor is too large"));
+        }
+        width = width.max(line.len());
+        height = row_idx + 1;
+
+        for (col_idx, char) in line.chars().enumerate() {
+            match char {
+                '@' => coords.insert((col_idx as i16, row_idx as i16), '@'),
+                _ => continue,
+            };
+        }
+    }
+
+    Ok(Floor {
+        rolls: coords,
+        width,
+        height,
+    })
+}
+
+pub fn part1(coords: &HashMap<(i16, i16), char>) -> usize {
+    let mut unblocked_count = 0;
+
+    for (x, y) in coords.keys() {
+        let mut adjecent = 0;
+
+        for y_i in (y - 1)..=(y + 1) {
+            for x_i in (x - 1)..=(x + 1) {
+                // check if potential adjecent roll exists and is not itself
+                if coords.contains_key(&(x_i, y_i)) && (x_i, y_i) != (*x, *y) {
+                    adjecent += 1;
+                }
+            }
+        }
+
+        if adjecent < 4 {
+            unblocked_count += 1;
+        }
+    }
+
+    unblocked_count
+}
+
+pub fn part2(coords: &HashMap<(i16, i16), char>) -> usize {
+    let mut coords = coords.clone();
+
+    peel_rolls(&mut coords).len()
+}
+
+// removes rolls until none are accessible, returns removed rolls in removal order
+pub fn peel_rolls(coords: &mut HashMap<(i16, i16), char>) -> Vec<(i16, i16)> {
+    let mut removed = Vec::new();
+
+    loop {
+        let prev_removed_count = removed.len();
+        for (x, y) in coords.keys().cloned().collect::<Vec<_>>() {
+            let mut adjecent = 0;
+
+            for y_i in (y - 1)..=(y + 1) {
+                for x_i in (x - 1)..=(x + 1) {
+                    // check if potential adjecent roll exists and is not itself
+                    if coords.contains_key(&(x_i, y_i)) && (x_i, y_i) != (x, y) {
+                        adjecent += 1;
+                    }
+                }
+            }
+
+            if adjecent < 4 {
+                removed.push((x, y));
+                // remove roll for next run
+                coords.remove(&(x, y));
+            }
+        }
+
+        // we were not able to remove anymore, removed count remains the same
+        if prev_removed_count == removed.len() {
+            break;
+        }
+    }
+
+    removed
+}
+
+// peels rolls with a work queue, only rechecking the neighbours of removed rolls
+pub fn part2_queue(coords: &HashMap<(i16, i16), char>) -> usize {
+    let neighbours = |(x, y): (i16, i16)| {
+        (y - 1..=y + 1)
+            .flat_map(move |y_i| (x - 1..=x + 1).map(move |x_i| (x_i, y_i)))
+            .filter(move |&p| p != (x, y))
+    };
+
+    // adjecent roll count for every roll
+    let mut adjecent: HashMap<(i16, i16), usize> = coords
+        .keys()
+        .map(|&p| (p, neighbours(p).filter(|n| coords.contains_key(n)).count()))
+        .collect();
+
+    let mut queue: VecDeque<(i16, i16)> = adjecent
+        .iter()
+        .filter(|(_, count)| **count < 4)
+        .map(|(p, _)| *p)
+        .collect();
+
+    let mut removed_count = 0;
+
+    while let Some(p) = queue.pop_front() {
+        // already removed via an earlier queue entry
+        if adjecent.remove(&p).is_none() {
+            continue;
+        }
+        removed_count += 1;
+
+        for n in neighbours(p) {
+            if let Some(count) = adjecent.get_mut(&n) {
+                *count -= 1;
+                // just became accessible
+                if *count == 3 {
+                    queue.push_back(n);
+                }
+            }
+        }
+    }
+
+    removed_count
+}
+
+// the grid with removed rolls in red and the rolls left behind in grey
+pub fn render(floor: &Floor) -> Grid {
+    let mut coords = floor.rolls.clone();
+    let removed = peel_rolls(&mut coords);
+
+    let mut grid = Grid::new(floor.width, floor.height, [240, 240, 240]);
+    for (x, y) in removed {
+        grid.set(x as usize, y as usize, [200, 40, 40]);
+    }
+    for (x, y) in coords.keys() {
+        grid.set(*x as usize, *y as usize, [80, 80, 80]);
+    }
+
+    grid
+}
+
+#[test]
+fn test_p1() {
+    let data = r"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    assert_eq!(part1(&parse_rolls(data).unwrap().rolls), 13)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    assert_eq!(part2(&parse_rolls(data).unwrap().rolls), 43)
+}
+
+#[test]
+fn test_p2_queue() {
+    let data = r"..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.";
+
+    assert_eq!(part2_queue(&parse_rolls(data).unwrap().rolls), 43)
+}
+
+#[test]
+fn test_render() {
+    let data = r"@@.
+.@.";
+
+    let image = render(&parse_rolls(data).unwrap()).to_ppm();
+    let header = b"P6\n12 8\n255\n";
+
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(image.len(), header.len() + 12 * 8 * 3)
+}
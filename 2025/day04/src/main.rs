@@ -0,0 +1,5 @@
+use aoc2025_day04::Day04;
+
+fn main() {
+    aoc::main::<Day04>();
+}
@@ -0,0 +1,194 @@
+use std::ops::RangeInclusive;
+
+use aoc::{Answer, Command, Error, Key, Solution, ranges::merge_ranges};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const KEY: Key = Key::new(2025, 5);
+
+    type Input = Inventory;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "linear scan of the ranges per id",
+        "sort and merge the ranges",
+    ];
+
+    fn parse(data: &str) -> Result<Inventory, Error> {
+        parse_inventory(data)
+    }
+
+    fn part1(inventory: &Inventory) -> Result<Answer, Error> {
+        Ok(part1(inventory).to_string())
+    }
+
+    fn part2(inventory: &Inventory) -> Result<Answer, Error> {
+        Ok(part2(inventory)?.to_string())
+    }
+
+    fn commands() -> Vec<Command<Inventory>> {
+        vec![Command {
+            name: "merged",
+            usage: "merged  the fresh ranges after merging the overlapping ones",
+            run: |inventory, _| {
+                let merged = merge_ranges(inventory.ranges.iter().cloned());
+                let ids = part2(inventory)?;
+
+                let mut lines: Vec<String> = merged
+                    .iter()
+                    .map(|range| format!("{}-{}", range.start(), range.end()))
+                    .collect();
+                lines.push(format!("{} ranges, {ids} ids", merged.len()));
+                Ok(lines.join("\n"))
+            },
+        }]
+    }
+}
+
+// the fresh ingredient id ranges and the available ingredient ids
+pub struct Inventory {
+    pub ranges: Vec<RangeInclusive<usize>>,
+    pub ids: Vec<usize>,
+}
+
+pub fn parse_inventory(data: &str) -> Result<Inventory, Error> {
+    let Some((ranges, ids)) = data.split_once("\n\n") else {
+        return Err(Error::Parse(
+            "no blank line between the ranges and the ids".to_string(),
+        ));
+    };
+
+    let ranges = ranges
+        .lines()
+        .enumerate()
+        .map(|(i, r)| {
+            let range = r.split_once("-").and_then(|(start, end)| {
+                let start: usize = start.parse().ok()?;
+                let end: usize = end.parse().ok()?;
+                (start <= end).then_some(start..=end)
+            });
+            range.ok_or_else(|| Error::at_line(i + 1, format!("bad range {r:?}")))
+        })
+        .collect::<Result<_, _>>()?;
+
+    // ids start after the ranges and the blank line
+    let first_id_line = data[..data.len() - ids.len()].lines().count() + 1;
+    let ids = ids
+        .lines()
+        .enumerate()
+        .map(|(i, id)| {
+            id.parse()
+                .map_err(|_| Error::at_line(first_id_line + i, format!("bad id {id:?}")))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Inventory { ranges, ids })
+}
+
+pub fn part1(inventory: &Inventory) -> usize {
+    let mut fresh_count = 0;
+
+    for &id_num in &inventory.ids {
+        let mut fresh = false;
+        for range in &inventory.ranges {
+            if range.contains(&id_num) {
+                fresh = true;
+                break;
+            }
+        }
+
+        if fresh {
+            fresh_count += 1;
+        }
+    }
+
+    fresh_count
+}
+
+pub fn part2(inventory: &Inventory) -> Result<usize, Error> {
+    // 0-18446744073709551615 alone has one id more than a usize counts
+    merge_ranges(inventory.ranges.iter().cloned())
+        .iter()
+        .try_fold(0usize, |fresh, range| {
+            (range.end() - range.start())
+                .checked_add(1)?
+                .checked_add(fresh)
+        })
+        .ok_or_else(|| Error::NoAnswer("the number of fresh ids overflows".to_string()))
+}
+
+#[test]
+fn test_p1() {
+    let data = r"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    assert_eq!(part1(&parse_inventory(data).unwrap()), 3)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32";
+
+    assert_eq!(part2(&parse_inventory(data).unwrap()).unwrap(), 14)
+}
+
+#[test]
+fn test_p2_no_ranges() {
+    let data = "\n\n1\n5";
+
+    assert_eq!(part2(&parse_inventory(data).unwrap()).unwrap(), 0)
+}
+
+#[test]
+fn test_bad_ranges() {
+    assert!(parse_inventory("5-3\n\n1").is_err());
+
+    let all = parse_inventory("0-18446744073709551615\n\n1").unwrap();
+    assert!(part2(&all).is_err())
+}
+
+#[test]
+fn test_snapshot_merged_ranges() {
+    let data = std::fs::read_to_string(Day05::KEY.fixture_path("example")).unwrap();
+    let inventory = parse_inventory(&data).unwrap();
+
+    let merged: Vec<String> = merge_ranges(inventory.ranges.iter().cloned())
+        .iter()
+        .map(|range| format!("{}-{}\n", range.start(), range.end()))
+        .collect();
+
+    aoc::snapshot::assert_snapshot(Day05::KEY, "merged_ranges", &merged.concat())
+}
+
+#[test]
+fn test_merged_command() {
+    let merged = |data: &str| (Day05::commands()[0].run)(&parse_inventory(data).unwrap(), "");
+
+    assert_eq!(
+        merged("3-5\n10-14\n16-20\n12-18\n\n1\n").unwrap(),
+        "3-5\n10-20\n2 ranges, 14 ids"
+    );
+    assert!(matches!(
+        merged("0-18446744073709551615\n\n1\n"),
+        Err(Error::NoAnswer(_))
+    ))
+}
@@ -0,0 +1,5 @@
+use aoc2025_day05::Day05;
+
+fn main() {
+    aoc::main::<Day05>();
+}
@@ -0,0 +1,262 @@
+use std::io::BufRead;
+
+use aoc::{Answer, Error, Key, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const KEY: Key = Key::new(2025, 6);
+
+    // part 1 reads the rows as a stream, part 2 needs the columns lined up
+    type Input = String;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "streamed column sums and checked products",
+        "problems split at blank columns, numbers read top down",
+    ];
+
+    fn parse(data: &str) -> Result<String, Error> {
+        Ok(data.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer, Error> {
+        Ok(part1(input.as_bytes())?.to_string())
+    }
+
+    fn part2(input: &String) -> Result<Answer, Error> {
+        Ok(part2(input)?.to_string())
+    }
+
+    fn stream(part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        match part {
+            1 => Some(part1(input).map(|total| total.to_string())),
+            _ => None,
+        }
+    }
+}
+
+pub fn part1(input: impl BufRead) -> Result<usize, Error> {
+    // running sum and product per column, the operator row at the bottom picks one.
+    // a sum or product that overflowed is None, which only matters if the column uses it
+    let mut sums: Vec<Option<usize>> = Vec::new();
+    let mut products: Vec<Option<usize>> = Vec::new();
+    let mut total: usize = 0;
+
+    for (i, row) in input.lines().enumerate() {
+        let row = row?;
+        for (index, val) in row.split_whitespace().enumerate() {
+            let (result, name) = match val {
+                "*" => (products.get(index), "product"),
+                "+" => (sums.get(index), "sum"),
+                _ => {
+                    let Ok(n) = val.parse::<usize>() else {
+                        return Err(Error::at_line(i + 1, format!("bad number {val:?}")));
+                    };
+                    if index == sums.len() {
+                        sums.push(Some(0));
+                        products.push(Some(1));
+                    }
+
+                    sums[index] = sums[index].and_then(|s| s.checked_add(n));
+                    products[index] = products[index].and_then(|p| p.checked_mul(n));
+                    continue;
+                }
+            };
+
+            let Some(&result) = result else {
+                return Err(Error::at_line(
+                    i + 1,
+                    format!("no numbers above operator {}", index + 1),
+                ));
+            };
+            let Some(result) = result else {
+                return Err(Error::NoAnswer(format!(
+                    "the {name} of column {} overflows",
+                    index + 1
+                )));
+            };
+            total = total
+                .checked_add(result)
+                .ok_or_else(|| Error::NoAnswer("the grand total overflows".to_string()))?;
+        }
+    }
+
+    Ok(total)
+}
+
+// a problem of the worksheet, its operator and the columns it spans
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProblemSpec {
+    pub symbol: char,
+    pub start: usize,
+    pub end: usize, // exclusive
+}
+
+pub fn part2(data: &str) -> Result<usize, Error> {
+    let mut totals: Vec<usize> = Vec::new();
+    let (grid, bottom_row) = worksheet(data);
+
+    for spec in problem_specs(&grid, &bottom_row) {
+        let width = spec.end - spec.start;
+        let mut num_s: Vec<String> = vec![String::new(); width];
+
+        for row in grid.iter() {
+            for (x, col) in row[spec.start..spec.end].iter().enumerate() {
+                if col.is_ascii_digit() {
+                    num_s[x].push(*col);
+                }
+            }
+        }
+
+        let columns = format!("columns {}..{}", spec.start + 1, spec.end);
+        let overflow = || Error::NoAnswer(format!("the problem in {columns} overflows"));
+
+        // filter out empty columns, digits only so parsing fails on overflow alone
+        let operands: Vec<usize> = num_s
+            .iter()
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<usize>().map_err(|_| overflow()))
+            .collect::<Result<_, _>>()?;
+
+        let problem_sum = match spec.symbol {
+            '*' => operands
+                .iter()
+                .try_fold(1usize, |sum, &val| sum.checked_mul(val)),
+            '+' => operands
+                .iter()
+                .try_fold(0usize, |sum, &val| sum.checked_add(val)),
+            _ => return Err(Error::Parse(format!("no operator under {columns}"))),
+        };
+
+        totals.push(problem_sum.ok_or_else(overflow)?);
+    }
+
+    totals
+        .iter()
+        .try_fold(0usize, |total, &problem| total.checked_add(problem))
+        .ok_or_else(|| Error::NoAnswer("the grand total overflows".to_string()))
+}
+
+// the digit rows padded to the same width, and the operator row below them
+pub fn worksheet(data: &str) -> (Vec<Vec<char>>, Vec<char>) {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+
+    // build grid and pad rows to same width
+    let lines: Vec<&str> = data.lines().collect();
+    let max_width = lines.iter().map(|l| l.len()).max().unwrap_or(0);
+
+    for row in &lines {
+        let mut cols: Vec<char> = row.chars().collect();
+        cols.resize(max_width, ' ');
+        grid.push(cols);
+    }
+
+    // remove last row (operators), an empty worksheet has none
+    let bottom_row = grid.pop().unwrap_or_default();
+
+    (grid, bottom_row)
+}
+
+// where the problems are, problems are separated by columns of spaces only
+pub fn problem_specs(grid: &[Vec<char>], bottom_row: &[char]) -> Vec<ProblemSpec> {
+    let max_width = bottom_row.len();
+
+    // check if column is a separator (all spaces in all rows)
+    let is_separator_col =
+        |col: usize| -> bool { grid.iter().all(|row| row[col] == ' ') && bottom_row[col] == ' ' };
+
+    let mut specs: Vec<ProblemSpec> = Vec::new();
+    let mut in_problem = false;
+    let mut problem_start = 0;
+    let mut problem_op = ' ';
+
+    // find problem boundaries
+    for i in 0..max_width {
+        if is_separator_col(i) {
+            if in_problem {
+                // end of current problem
+                specs.push(ProblemSpec {
+                    symbol: problem_op,
+                    start: problem_start,
+                    end: i,
+                });
+                in_problem = false;
+            }
+        } else {
+            if !in_problem {
+                // start of new problem
+                in_problem = true;
+                problem_start = i;
+                problem_op = ' ';
+            }
+
+            if bottom_row[i] == '+' || bottom_row[i] == '*' {
+                problem_op = bottom_row[i];
+            }
+        }
+    }
+    // finalize last problem if we ended inside one
+    if in_problem {
+        specs.push(ProblemSpec {
+            symbol: problem_op,
+            start: problem_start,
+            end: max_width,
+        });
+    }
+
+    specs
+}
+
+#[test]
+fn test_p1() {
+    let data = r"123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 4277556)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"123 328  51 64 
+ 45 64  387 23 
+  6 98  215 314
+*   +   *   +  ";
+
+    assert_eq!(part2(data).unwrap(), 3263827)
+}
+
+#[test]
+fn test_bad_worksheet() {
+    assert!(part1("1\n+ *".as_bytes()).is_err());
+    assert!(part1("1 x\n+ +".as_bytes()).is_err());
+    assert!(part2("12\n34").is_err());
+    assert_eq!(part2("").unwrap(), 0)
+}
+
+#[test]
+fn test_p1_overflow() {
+    let big = r"4294967296 4294967296
+4294967296 4294967296
++ *";
+    let sum_only = r"4294967296 1
+4294967296 1
++ *";
+
+    assert!(part1(big.as_bytes()).is_err());
+    assert_eq!(part1(sum_only.as_bytes()).unwrap(), 2 * 4294967296 + 1)
+}
+
+#[test]
+fn test_snapshot_problem_specs() {
+    let data = std::fs::read_to_string(Day06::KEY.fixture_path("example")).unwrap();
+    let (grid, bottom_row) = worksheet(&data);
+
+    let specs: Vec<String> = problem_specs(&grid, &bottom_row)
+        .iter()
+        .map(|spec| format!("{} {}..{}\n", spec.symbol, spec.start, spec.end))
+        .collect();
+
+    aoc::snapshot::assert_snapshot(Day06::KEY, "problem_specs", &specs.concat())
+}
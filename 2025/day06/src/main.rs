@@ -0,0 +1,5 @@
+use aoc2025_day06::Day06;
+
+fn main() {
+    aoc::main::<Day06>();
+}
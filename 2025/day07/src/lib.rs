@@ -0,0 +1,257 @@
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+use aoc::{
+    Answer, Error, Key, Render, Solution,
+    export::{Color, Grid},
+};
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const KEY: Key = Key::new(2025, 7);
+
+    // the manifold is read row by row while solving
+    type Input = String;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "beam set moved row by row",
+        "timeline counts per column, row by row",
+    ];
+
+    fn parse(data: &str) -> Result<String, Error> {
+        Ok(data.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer, Error> {
+        Ok(part1(input.as_bytes())?.to_string())
+    }
+
+    fn part2(input: &String) -> Result<Answer, Error> {
+        Ok(part2(input.as_bytes())?.to_string())
+    }
+
+    fn render(input: &String) -> Result<Render, Error> {
+        Ok(Render::Grid(render(input)))
+    }
+
+    fn stream(part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        match part {
+            1 => Some(part1(input).map(|splits| splits.to_string())),
+            2 => Some(part2(input).map(|timelines| timelines.to_string())),
+            _ => None,
+        }
+    }
+}
+
+pub fn part1(input: impl BufRead) -> Result<usize, Error> {
+    let mut split_count = 0;
+    let mut beams: HashSet<usize> = HashSet::new();
+
+    for row in input.lines() {
+        let (next_beams, splits) = step_beams(&row?, &beams);
+        beams = next_beams;
+        split_count += splits;
+    }
+
+    Ok(split_count)
+}
+
+// moves the beams coming from the row above through this row,
+// returns the columns with a beam leaving the row and how many splitters were hit
+pub fn step_beams(row: &str, beams: &HashSet<usize>) -> (HashSet<usize>, usize) {
+    let mut split_count = 0;
+    let mut next_beams: HashSet<usize> = HashSet::new();
+
+    for (x, val) in row.chars().enumerate() {
+        let beam_above = beams.contains(&x);
+        if val == 'S' {
+            // starting beam below S
+            next_beams.insert(x);
+        } else if val == '^' {
+            // hit splitter with | above it
+            if beam_above {
+                // fill out new lasers, a beam split off the left edge leaves the grid
+                if x > 0 {
+                    next_beams.insert(x - 1);
+                }
+                next_beams.insert(x + 1);
+
+                split_count += 1
+            }
+        } else if beam_above {
+            // beam continues
+            next_beams.insert(x);
+        }
+    }
+
+    (next_beams, split_count)
+}
+
+pub fn part2(input: impl BufRead) -> Result<usize, Error> {
+    // track how many timelines have a particle at each column
+    let mut timelines: HashMap<usize, usize> = HashMap::new();
+    let mut total_timelines = 0;
+
+    for row in input.lines() {
+        let row = row?;
+        let width = row.len();
+        let mut next_timelines: HashMap<usize, usize> = HashMap::new();
+
+        for (x, val) in row.chars().enumerate() {
+            if val == 'S' {
+                // start with 1 timeline at this column
+                next_timelines.insert(x, 1);
+            } else if val == '^' {
+                // check if any timelines have particles arriving here
+                if let Some(&count) = timelines.get(&x) {
+                    // timeline splits: count timelines go left, count go right
+                    if x > 0 {
+                        add_timelines(next_timelines.entry(x - 1).or_insert(0), count)?;
+                    } else {
+                        // left edge
+                        add_timelines(&mut total_timelines, count)?;
+                    }
+                    if x + 1 < width {
+                        add_timelines(next_timelines.entry(x + 1).or_insert(0), count)?;
+                    } else {
+                        // right edge
+                        add_timelines(&mut total_timelines, count)?;
+                    }
+                }
+            } else if val == '.'
+                && let Some(&count) = timelines.get(&x)
+            {
+                add_timelines(next_timelines.entry(x).or_insert(0), count)?;
+            }
+        }
+
+        timelines = next_timelines;
+    }
+
+    // add timelines still active (exited bottom of grid)
+    for &count in timelines.values() {
+        add_timelines(&mut total_timelines, count)?;
+    }
+    Ok(total_timelines)
+}
+
+// every splitter doubles the timelines, enough of them count past a usize
+fn add_timelines(timelines: &mut usize, count: usize) -> Result<(), Error> {
+    *timelines = timelines
+        .checked_add(count)
+        .ok_or_else(|| Error::NoAnswer("the number of timelines overflows".to_string()))?;
+    Ok(())
+}
+
+// the manifold with the beam paths drawn in
+pub fn render(data: &str) -> Grid {
+    const EMPTY: Color = [240, 240, 240];
+
+    let height = data.lines().count();
+    let width = data.lines().map(|l| l.len()).max().unwrap_or(0);
+
+    let mut grid = Grid::new(width, height, EMPTY);
+    for (y, row) in data.lines().enumerate() {
+        for (x, val) in row.chars().enumerate() {
+            match val {
+                'S' => grid.set(x, y, [40, 160, 40]),
+                '^' => grid.set(x, y, [40, 40, 40]),
+                _ => (),
+            }
+        }
+    }
+
+    let mut beams: HashSet<usize> = HashSet::new();
+    for (y, row) in data.lines().enumerate() {
+        // beams passing through this row, split beams leave next to the splitter
+        let (next_beams, _) = step_beams(row, &beams);
+        for &x in beams.iter().chain(next_beams.iter()) {
+            if x < width && grid.get(x, y) == EMPTY {
+                grid.set(x, y, [230, 180, 30]);
+            }
+        }
+        beams = next_beams;
+    }
+
+    grid
+}
+
+#[test]
+fn test_p1() {
+    let data = r".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 21)
+}
+
+#[test]
+fn test_p2() {
+    let data = r".......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............";
+
+    assert_eq!(part2(data.as_bytes()).unwrap(), 40)
+}
+
+#[test]
+fn test_render() {
+    let data = r"..S..
+.....
+..^..
+.....";
+
+    let image = render(data).to_ppm();
+    let header = b"P6\n20 16\n255\n";
+
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(image.len(), header.len() + 20 * 16 * 3)
+}
+
+#[test]
+fn test_p1_left_edge() {
+    let data = r"S..
+...
+^..
+...";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 1)
+}
+
+#[test]
+fn test_p2_overflow() {
+    // every pair of rows doubles the timelines in the middle column
+    let data = format!(".S.\n{}", ".^.\n^.^\n".repeat(70));
+
+    assert!(part2(data.as_bytes()).is_err());
+    assert!(part2(&data.as_bytes()[..4 + 8 * 30]).is_ok())
+}
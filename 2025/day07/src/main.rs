@@ -0,0 +1,7 @@
+use aoc2025_day07::Day07;
+
+aoc::count_allocations!();
+
+fn main() {
+    aoc::main::<Day07>();
+}
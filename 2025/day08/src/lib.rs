@@ -0,0 +1,372 @@
+use std::collections::HashMap;
+
+use aoc::{Answer, Command, Error, Key, Render, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const KEY: Key = Key::new(2025, 8);
+
+    type Input = Vec<Point>;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "sorted pair distances, union-find over the closest pairs",
+        "Kruskal until one circuit is left",
+    ];
+
+    fn parse(data: &str) -> Result<Vec<Point>, Error> {
+        parse_points(data)
+    }
+
+    fn part1(points: &Vec<Point>) -> Result<Answer, Error> {
+        Ok(part1(points).to_string())
+    }
+
+    fn part2(points: &Vec<Point>) -> Result<Answer, Error> {
+        if points.len() < 2 {
+            return Err(Error::NoAnswer(
+                "no connection without two junction boxes".to_string(),
+            ));
+        }
+
+        Ok(part2(points).to_string())
+    }
+
+    fn commands() -> Vec<Command<Vec<Point>>> {
+        vec![Command {
+            name: "circuit",
+            usage: "circuit <line> <k>  the circuit of the junction box on a line after k connections",
+            run: |points, args| {
+                let numbers: Vec<usize> = args
+                    .split_whitespace()
+                    .map(|arg| arg.parse())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| Error::Parse(format!("{args:?} is not <line> <k>")))?;
+                let &[line, connections] = &numbers[..] else {
+                    return Err(Error::Parse(format!("{args:?} is not <line> <k>")));
+                };
+                if line == 0 || line > points.len() {
+                    return Err(Error::Parse(format!(
+                        "there are junction boxes on lines 1 to {}",
+                        points.len()
+                    )));
+                }
+
+                let mut uf = connect(points, connections);
+                let root = uf.find(line - 1);
+                let members: Vec<String> = (0..points.len())
+                    .filter(|&i| uf.find(i) == root)
+                    .map(|i| (i + 1).to_string())
+                    .collect();
+
+                let Point { x, y, z } = points[line - 1];
+                Ok(format!(
+                    "{x},{y},{z} is in a circuit of {} after {connections} connections, lines {}",
+                    members.len(),
+                    members.join(" ")
+                ))
+            },
+        }]
+    }
+
+    fn render(points: &Vec<Point>) -> Result<Render, Error> {
+        Ok(Render::Svg(render(points)))
+    }
+}
+
+type PointIndex = usize;
+type Distance = usize;
+
+#[derive(Debug, Clone)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+impl Point {
+    pub fn distance(&self, other: &Point) -> Distance {
+        // use squared euclidean distance to avoid sqrt op
+        self.x.abs_diff(other.x).pow(2)
+            + self.y.abs_diff(other.y).pow(2)
+            + self.z.abs_diff(other.z).pow(2)
+    }
+}
+
+pub struct UnionFind {
+    parent: Vec<PointIndex>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(size: usize) -> Self {
+        let mut parent = vec![0; size];
+        for i in 0..size {
+            parent[i] = i; // Each element is initially its own parent
+        }
+
+        UnionFind {
+            parent,
+            size: vec![1; size],
+        }
+    }
+
+    pub fn find(&mut self, i: usize) -> usize {
+        let mut i = i;
+        while self.parent[i] != i {
+            i = self.parent[i]
+        }
+        i
+    }
+
+    pub fn union(&mut self, i: usize, j: usize) {
+        let root_i = self.find(i);
+        let root_j = self.find(j);
+        if root_i != root_j {
+            if self.size[root_i] < self.size[root_j] {
+                self.parent[root_i] = root_j;
+                self.size[root_j] += self.size[root_i];
+            } else {
+                self.parent[root_j] = root_i;
+                self.size[root_i] += self.size[root_j];
+            }
+        }
+    }
+}
+
+// 1000 shortest distance pairs are connected in part 1, 10 in the example which only
+// has 20 junction boxes
+pub fn connections_to_make(points: &[Point]) -> usize {
+    if points.len() <= 20 { 10 } else { 1000 }
+}
+
+// squared distances of coordinates below this still fit in a usize
+const MAX_COORDINATE: usize = 1_000_000_000;
+
+pub fn parse_points(data: &str) -> Result<Vec<Point>, Error> {
+    let mut points: Vec<Point> = Vec::new();
+    for (i, row) in data.lines().enumerate() {
+        let vals = row
+            .split(",")
+            .map(|s| s.trim().parse().ok().filter(|&v| v < MAX_COORDINATE))
+            .collect::<Option<Vec<usize>>>();
+
+        let Some(&[x, y, z]) = vals.as_deref() else {
+            return Err(Error::at_line(
+                i + 1,
+                format!("expected x,y,z below {MAX_COORDINATE}, got {row:?}"),
+            ));
+        };
+
+        points.push(Point { x, y, z });
+    }
+
+    Ok(points)
+}
+
+// distance between all pair of points, shortest first
+pub fn sorted_distances(points: &[Point]) -> Vec<(PointIndex, PointIndex, Distance)> {
+    let mut distance_between: Vec<(PointIndex, PointIndex, Distance)> = Vec::new();
+
+    for p1_index in 0..points.len() {
+        for p2_index in (p1_index + 1)..points.len() {
+            distance_between.push((
+                p1_index,
+                p2_index,
+                points[p1_index].distance(&points[p2_index]),
+            ));
+        }
+    }
+
+    distance_between.sort_by_key(|&(_, _, distance)| distance);
+
+    distance_between
+}
+
+pub fn part1(points: &[Point]) -> usize {
+    let mut uf = connect(points, connections_to_make(points));
+
+    circuit_sizes(&mut uf).iter().take(3).product()
+}
+
+// the circuits after connecting the given number of closest pairs
+pub fn connect(points: &[Point], connections: usize) -> UnionFind {
+    let distance_between = sorted_distances(points);
+
+    let mut uf = UnionFind::new(points.len());
+
+    // shortest distance pairs, union them to build curcuits
+    for (p1_idx, p2_idx, _distance) in distance_between.iter().take(connections) {
+        uf.union(*p1_idx, *p2_idx);
+    }
+
+    uf
+}
+
+// size of every circuit, largest first
+pub fn circuit_sizes(uf: &mut UnionFind) -> Vec<usize> {
+    let mut curcuits: HashMap<usize, usize> = HashMap::new();
+
+    for point_idx in 0..uf.parent.len() {
+        let parent = uf.find(point_idx);
+        *curcuits.entry(parent).or_insert(0) += 1;
+    }
+
+    let mut sorted_curcuits_by_size: Vec<usize> = curcuits.values().cloned().collect();
+
+    // descending, largest first
+    sorted_curcuits_by_size.sort();
+    sorted_curcuits_by_size.reverse();
+
+    sorted_curcuits_by_size
+}
+
+pub fn part2(points: &[Point]) -> usize {
+    let distance_between = sorted_distances(points);
+
+    let mut uf = UnionFind::new(points.len());
+
+    let mut last_connection = (0, 0);
+    for (p1_idx, p2_idx, _distance) in distance_between.iter() {
+        // actual merge of different circuits
+        if uf.find(*p1_idx) != uf.find(*p2_idx) {
+            uf.union(*p1_idx, *p2_idx);
+            last_connection = (*p1_idx, *p2_idx);
+        }
+    }
+
+    let p1 = &points[last_connection.0];
+    let p2 = &points[last_connection.1];
+
+    p1.x * p2.x
+}
+
+// SVG of the junction boxes seen from above (x/y) with the part 1 connections,
+// every box is coloured by the circuit it ends up in
+pub fn render(points: &[Point]) -> String {
+    let distance_between = sorted_distances(points);
+
+    let mut uf = UnionFind::new(points.len());
+    let connections = &distance_between[..connections_to_make(points).min(distance_between.len())];
+    for (p1_idx, p2_idx, _distance) in connections {
+        uf.union(*p1_idx, *p2_idx);
+    }
+
+    let width = points.iter().map(|p| p.x).max().unwrap_or(0).max(1);
+    let height = points.iter().map(|p| p.y).max().unwrap_or(0).max(1);
+    let radius = width.max(height) / 200 + 1;
+
+    let mut svg =
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width} {height}\">\n");
+
+    for (p1_idx, p2_idx, _distance) in connections {
+        let (p1, p2) = (&points[*p1_idx], &points[*p2_idx]);
+        svg += &format!(
+            "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"#888\" stroke-width=\"{}\"/>\n",
+            p1.x,
+            p1.y,
+            p2.x,
+            p2.y,
+            radius / 2 + 1
+        );
+    }
+
+    for (point_idx, point) in points.iter().enumerate() {
+        // spread circuit roots around the colour wheel
+        let hue = uf.find(point_idx) * 137 % 360;
+        svg += &format!(
+            "<circle cx=\"{}\" cy=\"{}\" r=\"{radius}\" fill=\"hsl({hue},70%,45%)\"/>\n",
+            point.x, point.y
+        );
+    }
+
+    svg += "</svg>\n";
+    svg
+}
+
+#[test]
+fn test_p1() {
+    let data = r"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    assert_eq!(part1(&parse_points(data).unwrap()), 40)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689";
+
+    assert_eq!(part2(&parse_points(data).unwrap()), 25272)
+}
+
+#[test]
+fn test_render() {
+    let data = r"0,0,0
+10,10,10
+400,400,400";
+
+    let svg = render(&parse_points(data).unwrap());
+
+    assert!(svg.starts_with("<svg"));
+    assert_eq!(svg.matches("<circle").count(), 3);
+    assert_eq!(svg.matches("<line").count(), 3)
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(parse_points("1,2,3\n4,5").is_err());
+    assert!(parse_points("1,2,x").is_err());
+    assert!(parse_points("1,2,1000000000").is_err());
+    assert_eq!(parse_points("").unwrap().len(), 0)
+}
+
+#[test]
+fn test_snapshot_circuit_sizes() {
+    let data = std::fs::read_to_string(Day08::KEY.fixture_path("example")).unwrap();
+    let points = parse_points(&data).unwrap();
+
+    let mut uf = connect(&points, connections_to_make(&points));
+    let sizes: Vec<String> = circuit_sizes(&mut uf)
+        .iter()
+        .map(|size| format!("{size}\n"))
+        .collect();
+
+    aoc::snapshot::assert_snapshot(Day08::KEY, "circuit_sizes", &sizes.concat())
+}
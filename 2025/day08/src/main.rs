@@ -0,0 +1,7 @@
+use aoc2025_day08::Day08;
+
+aoc::count_allocations!();
+
+fn main() {
+    aoc::main::<Day08>();
+}
@@ -0,0 +1,337 @@
+use std::{
+    cmp::{max, min},
+    collections::HashMap,
+};
+
+use aoc::{Answer, Error, Key, Render, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const KEY: Key = Key::new(2025, 9);
+
+    type Input = Vec<Point>;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "all pairs of red tiles",
+        "coordinate compression and scanline fill, rectangles checked against it",
+    ];
+
+    fn parse(data: &str) -> Result<Vec<Point>, Error> {
+        parse_red_points(data)
+    }
+
+    fn part1(red_points: &Vec<Point>) -> Result<Answer, Error> {
+        if red_points.len() < 2 {
+            return Err(Error::NoAnswer(
+                "no rectangle without two red tiles".to_string(),
+            ));
+        }
+
+        Ok(part1(red_points).to_string())
+    }
+
+    fn part2(red_points: &Vec<Point>) -> Result<Answer, Error> {
+        Ok(part2(red_points).to_string())
+    }
+
+    fn render(red_points: &Vec<Point>) -> Result<Render, Error> {
+        Ok(Render::Svg(render(red_points)))
+    }
+}
+
+pub type Point = (usize, usize);
+
+pub fn part1(points: &[Point]) -> usize {
+    let mut points_rec_size: HashMap<(Point, Point), usize> = HashMap::new();
+
+    // calc size of all
+    for p1_idx in 0..points.len() {
+        for p2_idx in (p1_idx + 1)..points.len() {
+            let p1 = points[p1_idx];
+            let p2 = points[p2_idx];
+
+            // add self to x and y before area calc
+            let rec_size = (p2.0.abs_diff(p1.0) + 1) * (p2.1.abs_diff(p1.1) + 1);
+
+            points_rec_size.insert((p1, p2), rec_size);
+        }
+    }
+
+    *points_rec_size.values().max().unwrap()
+}
+
+// Represents a vertical edge in compressed coordinates
+#[derive(Debug)]
+struct VerticalEdge {
+    x: usize,     // compressed x index
+    y_min: usize, // compressed y index min
+    y_max: usize, // compressed y index max
+}
+
+pub fn part2(red_points: &[Point]) -> usize {
+    largest_valid_rectangle(red_points).0
+}
+
+// areas of coordinates below this still fit in a usize
+const MAX_COORDINATE: usize = 1_000_000_000;
+
+pub fn parse_red_points(data: &str) -> Result<Vec<Point>, Error> {
+    let mut red_points: Vec<Point> = Vec::new();
+    for (i, row) in data.lines().enumerate() {
+        let coord = row
+            .split(",")
+            .map(|s| s.trim().parse().ok().filter(|&v| v < MAX_COORDINATE))
+            .collect::<Option<Vec<usize>>>();
+
+        let Some(&[x, y]) = coord.as_deref() else {
+            return Err(Error::at_line(
+                i + 1,
+                format!("expected x,y below {MAX_COORDINATE}, got {row:?}"),
+            ));
+        };
+        red_points.push((x, y));
+    }
+
+    Ok(red_points)
+}
+
+// the polygon in compressed coordinates, a cell per distinct red x and y. a cell is valid
+// when it is red, green or inside the polygon
+pub struct CompressedGrid {
+    pub unique_x: Vec<usize>,
+    pub unique_y: Vec<usize>,
+    pub red: Vec<(usize, usize)>,
+    pub valid: Vec<Vec<bool>>, // indexed [x][y]
+}
+
+impl CompressedGrid {
+    // one row per compressed y, # for a valid cell
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for y in 0..self.unique_y.len() {
+            for column in &self.valid {
+                text.push(if column[y] { '#' } else { '.' });
+            }
+            text.push('\n');
+        }
+        text
+    }
+}
+
+pub fn compress(red_points: &[Point]) -> CompressedGrid {
+    // unique x,y coordinates
+    let mut unique_x: Vec<usize> = red_points.iter().map(|p| p.0).collect();
+    let mut unique_y: Vec<usize> = red_points.iter().map(|p| p.1).collect();
+    unique_x.sort();
+    unique_x.dedup();
+    unique_y.sort();
+    unique_y.dedup();
+
+    // original coord -> compressed index
+    let x_to_idx: HashMap<usize, usize> = unique_x
+        .iter()
+        .enumerate()
+        .map(|(idx, &x)| (x, idx))
+        .collect();
+    let y_to_idx: HashMap<usize, usize> = unique_y
+        .iter()
+        .enumerate()
+        .map(|(idx, &y)| (y, idx))
+        .collect();
+
+    // red points to compressed coordinates
+    let compressed_red: Vec<(usize, usize)> = red_points
+        .iter()
+        .map(|&(x, y)| (x_to_idx[&x], y_to_idx[&y]))
+        .collect();
+
+    let grid_width = unique_x.len();
+    let grid_height = unique_y.len();
+
+    // 2d grid instead of HashSet for faster access
+    let mut valid: Vec<Vec<bool>> = vec![vec![false; grid_height]; grid_width];
+    let mut vertical_edges: Vec<VerticalEdge> = Vec::new();
+
+    for i in 0..compressed_red.len() {
+        let (x1, y1) = compressed_red[i];
+        let (x2, y2) = compressed_red[(i + 1) % compressed_red.len()];
+
+        // red points valid
+        valid[x1][y1] = true;
+        valid[x2][y2] = true;
+
+        if x1 == x2 {
+            vertical_edges.push(VerticalEdge {
+                x: x1,
+                y_min: min(y1, y2),
+                y_max: max(y1, y2),
+            });
+            for y in min(y1, y2)..=max(y1, y2) {
+                valid[x1][y] = true;
+            }
+        } else {
+            for x in min(x1, x2)..=max(x1, x2) {
+                valid[x][y1] = true;
+            }
+        }
+    }
+
+    // fill interior using scanline algorithm (in compressed space)
+    for y in 0..grid_height {
+        let mut crossings: Vec<usize> = vertical_edges
+            .iter()
+            .filter(|edge| edge.y_min <= y && y < edge.y_max)
+            .map(|edge| edge.x)
+            .collect();
+
+        crossings.sort();
+
+        for chunk in crossings.chunks(2) {
+            if chunk.len() == 2 {
+                let x_start = chunk[0];
+                let x_end = chunk[1];
+                for x in x_start..=x_end {
+                    valid[x][y] = true;
+                }
+            }
+        }
+    }
+
+    CompressedGrid {
+        unique_x,
+        unique_y,
+        red: compressed_red,
+        valid,
+    }
+}
+
+// area of the largest rectangle with red corners that stays inside the polygon, and its corners
+pub fn largest_valid_rectangle(red_points: &[Point]) -> (usize, Point, Point) {
+    let CompressedGrid {
+        unique_x,
+        unique_y,
+        red: compressed_red,
+        valid,
+    } = compress(red_points);
+
+    // find largest valid rectangle with red corners
+    let mut max_area: usize = 0;
+    let mut max_corners: (Point, Point) = ((0, 0), (0, 0));
+
+    for i in 0..compressed_red.len() {
+        for j in (i + 1)..compressed_red.len() {
+            let (cx1, cy1) = compressed_red[i];
+            let (cx2, cy2) = compressed_red[j];
+
+            let x_min = min(cx1, cx2);
+            let x_max = max(cx1, cx2);
+            let y_min = min(cy1, cy2);
+            let y_max = max(cy1, cy2);
+
+            // check if all cells in compressed rectangle are valid
+            let all_valid = (x_min..=x_max).all(|x| (y_min..=y_max).all(|y| valid[x][y]));
+
+            if all_valid {
+                // calculate actual area using original coords
+                let real_x1 = unique_x[x_min];
+                let real_x2 = unique_x[x_max];
+                let real_y1 = unique_y[y_min];
+                let real_y2 = unique_y[y_max];
+
+                let area = (real_x2 - real_x1 + 1) * (real_y2 - real_y1 + 1);
+                if area > max_area {
+                    max_area = area;
+                    max_corners = ((real_x1, real_y1), (real_x2, real_y2));
+                }
+            }
+        }
+    }
+
+    (max_area, max_corners.0, max_corners.1)
+}
+
+// SVG of the red/green polygon with the largest valid rectangle on top
+pub fn render(red_points: &[Point]) -> String {
+    let (_, (x1, y1), (x2, y2)) = largest_valid_rectangle(red_points);
+
+    let width = red_points.iter().map(|p| p.0).max().unwrap_or(0) + 1;
+    let height = red_points.iter().map(|p| p.1).max().unwrap_or(0) + 1;
+    let stroke = width.max(height) / 500 + 1;
+
+    let polygon: Vec<String> = red_points.iter().map(|(x, y)| format!("{x},{y}")).collect();
+
+    let mut svg =
+        format!("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width} {height}\">\n");
+    svg += &format!(
+        "<polygon points=\"{}\" fill=\"#7c7\" stroke=\"#c33\" stroke-width=\"{stroke}\"/>\n",
+        polygon.join(" ")
+    );
+    svg += &format!(
+        "<rect x=\"{x1}\" y=\"{y1}\" width=\"{}\" height=\"{}\" fill=\"#36c\" fill-opacity=\"0.5\" stroke=\"#36c\" stroke-width=\"{stroke}\"/>\n",
+        x2 - x1,
+        y2 - y1
+    );
+    svg += "</svg>\n";
+
+    svg
+}
+
+#[test]
+fn test_p1() {
+    let data = r"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+    assert_eq!(part1(&parse_red_points(data).unwrap()), 50)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+    assert_eq!(part2(&parse_red_points(data).unwrap()), 24)
+}
+
+#[test]
+fn test_render() {
+    let data = r"7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3";
+
+    let svg = render(&parse_red_points(data).unwrap());
+
+    assert!(svg.contains(r#"<rect x="2" y="3" width="7" height="2""#))
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(parse_red_points("1,2\n3").is_err());
+    assert!(parse_red_points("1,2,3").is_err());
+    assert!(parse_red_points("1000000000,2").is_err())
+}
+
+#[test]
+fn test_snapshot_valid_grid() {
+    let data = std::fs::read_to_string(Day09::KEY.fixture_path("example")).unwrap();
+    let red_points = parse_red_points(&data).unwrap();
+
+    aoc::snapshot::assert_snapshot(Day09::KEY, "valid_grid", &compress(&red_points).to_text())
+}
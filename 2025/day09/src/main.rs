@@ -0,0 +1,7 @@
+use aoc2025_day09::Day09;
+
+aoc::count_allocations!();
+
+fn main() {
+    aoc::main::<Day09>();
+}
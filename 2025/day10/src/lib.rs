@@ -0,0 +1,668 @@
+use std::{cmp::min, io::BufRead, str::FromStr};
+
+use aoc::{Answer, Command, Error, Key, Solution, Variant};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const KEY: Key = Key::new(2025, 10);
+
+    // machines are read line by line while solving
+    type Input = String;
+
+    const ALGORITHMS: [&'static str; 2] = [
+        "brute force over button subsets as bit masks",
+        "Gaussian elimination in floating point, search over the free buttons",
+    ];
+
+    fn parse(data: &str) -> Result<String, Error> {
+        Ok(data.to_string())
+    }
+
+    fn part1(input: &String) -> Result<Answer, Error> {
+        Ok(part1(input.as_bytes())?.to_string())
+    }
+
+    fn part2(input: &String) -> Result<Answer, Error> {
+        Ok(part2(input.as_bytes())?.to_string())
+    }
+
+    fn stream(part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        match part {
+            1 => Some(part1(input).map(|presses| presses.to_string())),
+            2 => Some(part2(input).map(|presses| presses.to_string())),
+            _ => None,
+        }
+    }
+
+    fn commands() -> Vec<Command<String>> {
+        vec![Command {
+            name: "machine",
+            usage: "machine <line>  solve a single machine line, lights and joltages",
+            run: |_, row| {
+                let lights = match min_light_presses(row) {
+                    Ok(presses) => Some(presses as usize),
+                    Err(Error::NoAnswer(_)) => None,
+                    Err(e) => return Err(e),
+                };
+                let (buttons, joltages) = parse_counters(row)?;
+                let joltage = solve_exact(&buttons, &joltages)?;
+
+                let show = |presses: Option<usize>| {
+                    presses.map_or("can't be reached".to_string(), |n| format!("{n} presses"))
+                };
+                Ok(format!(
+                    "lights: {}, joltages: {}",
+                    show(lights),
+                    show(joltage)
+                ))
+            },
+        }]
+    }
+
+    fn variants() -> Vec<Variant<String>> {
+        vec![
+            Variant {
+                name: "float",
+                part: 2,
+                solve: |input| Ok(part2(input.as_bytes())?.to_string()),
+            },
+            Variant {
+                name: "exact",
+                part: 2,
+                solve: |input| Ok(part2_exact(input.as_bytes())?.to_string()),
+            },
+        ]
+    }
+}
+
+pub fn part1(input: impl BufRead) -> Result<usize, Error> {
+    let mut min_pressed_total = 0;
+    for (i, row) in input.lines().enumerate() {
+        min_pressed_total += min_light_presses(&row?).map_err(|e| e.on_line(i + 1))? as usize;
+    }
+
+    Ok(min_pressed_total)
+}
+
+// lights of a machine, one bit each in the light and button masks
+const MAX_LIGHTS: usize = 64;
+
+// every combination of buttons is tried, counted in a u32
+const MAX_BUTTONS: usize = 31;
+
+// fewest button presses that turn on the lights of one machine
+pub fn min_light_presses(row: &str) -> Result<u32, Error> {
+    let mut parts = row.split_whitespace();
+
+    // parse target pattern bit mask, one bit per light
+    let Some(lights) = parts
+        .next()
+        .and_then(|lights| lights.strip_prefix('[')?.strip_suffix(']'))
+    else {
+        return Err(Error::Parse(format!("no lights in {row:?}")));
+    };
+    if lights.len() > MAX_LIGHTS {
+        return Err(Error::Parse(format!(
+            "more than {MAX_LIGHTS} lights in {row:?}"
+        )));
+    }
+
+    let mut target: u64 = 0;
+    for (pos, c) in lights.chars().enumerate() {
+        match c {
+            '.' => (),
+            '#' => target |= 1 << pos,
+            _ => return Err(Error::Parse(format!("bad light {c:?} in {row:?}"))),
+        }
+    }
+
+    let mut button_masks: Vec<u64> = Vec::new();
+
+    for part in parts {
+        if part.starts_with('{') {
+            break;
+        }
+
+        let mut button_mask: u64 = 0;
+        for i in parse_button(part)? {
+            // set index to bit, eg (0,3,4) 0b00011001
+            button_mask |= 1 << i;
+        }
+
+        button_masks.push(button_mask)
+    }
+
+    let nr_of_buttons = button_masks.len();
+    if nr_of_buttons > MAX_BUTTONS {
+        return Err(Error::Unsupported(format!(
+            "{nr_of_buttons} buttons, part 1 tries every combination of at most {MAX_BUTTONS}"
+        )));
+    }
+    // there is 2^nr_of_buttons combinations
+    let two_pow_n = 1u32 << nr_of_buttons;
+
+    let mut min_pressed_buttons = u32::MAX;
+    for combo in 0..two_pow_n {
+        let mut result = 0;
+
+        // check all buttons
+        for i in 0..nr_of_buttons {
+            // extract bit nr i from combo
+            if (combo >> i & 1) != 0 {
+                // XOR button mask with result
+                result ^= button_masks[i];
+            }
+        }
+
+        if result == target {
+            min_pressed_buttons = min(min_pressed_buttons, combo.count_ones());
+        }
+    }
+
+    if min_pressed_buttons == u32::MAX {
+        return Err(Error::NoAnswer(
+            "no combination of buttons turns on the lights".to_string(),
+        ));
+    }
+
+    Ok(min_pressed_buttons)
+}
+
+// the lights a button toggles, eg (0,3,4)
+fn parse_button(item: &str) -> Result<Vec<u8>, Error> {
+    let button: Vec<u8> =
+        bracketed(item, '(', ')').ok_or_else(|| Error::Parse(format!("bad button {item:?}")))?;
+    if button.iter().any(|&i| i as usize >= MAX_LIGHTS) {
+        return Err(Error::Parse(format!(
+            "button {item:?} is past the last of {MAX_LIGHTS} lights"
+        )));
+    }
+
+    Ok(button)
+}
+
+// the comma separated numbers of item between open and close
+fn bracketed<T: FromStr>(item: &str, open: char, close: char) -> Option<Vec<T>> {
+    let numbers = item.strip_prefix(open)?.strip_suffix(close)?;
+    numbers.split(',').map(|n| n.parse().ok()).collect()
+}
+
+pub fn part2(input: impl BufRead) -> Result<usize, Error> {
+    let mut min_pressed_total: usize = 0;
+    for (i, row) in input.lines().enumerate() {
+        let (buttons, joltages) = parse_counters(&row?).map_err(|e| e.on_line(i + 1))?;
+
+        // Build matrix A where A[counter][button] = 1 if button affects counter
+        let num_counters = joltages.len();
+        let num_buttons = buttons.len();
+
+        // Use Gaussian elimination to solve Ax = b (over rationals, then check integer)
+        // A is num_counters x num_buttons, x is button presses, b is joltages
+
+        let min_presses = solve_linear(&buttons, &joltages, num_counters, num_buttons);
+        if min_presses == usize::MAX {
+            return Err(Error::NoAnswer(format!(
+                "machine {} can't reach its joltages",
+                i + 1
+            )));
+        }
+        min_pressed_total = add_presses(min_pressed_total, min_presses)?;
+    }
+
+    Ok(min_pressed_total)
+}
+
+fn add_presses(total: usize, presses: usize) -> Result<usize, Error> {
+    total
+        .checked_add(presses)
+        .ok_or_else(|| Error::NoAnswer("the total of presses overflows".to_string()))
+}
+
+// the buttons with the counters they add to, and the joltage targets of the counters
+pub fn parse_counters(row: &str) -> Result<(Vec<Vec<u8>>, Vec<usize>), Error> {
+    let mut buttons: Vec<Vec<u8>> = Vec::new();
+    let mut joltages: Vec<usize> = Vec::new();
+
+    // skip first item
+    for item in row.split_whitespace().skip(1) {
+        // button
+        if item.starts_with('(') {
+            buttons.push(parse_button(item)?);
+        } else {
+            // joltage, a counter per light. below a u32 the search bounds and the
+            // eliminated matrices stay far from overflowing
+            let counters: Vec<u32> = bracketed(item, '{', '}')
+                .filter(|counters: &Vec<u32>| counters.len() <= MAX_LIGHTS)
+                .ok_or_else(|| Error::Parse(format!("bad joltages {item:?}")))?;
+            joltages.extend(counters.iter().map(|&joltage| joltage as usize));
+        }
+    }
+
+    if joltages.is_empty() {
+        return Err(Error::Parse(format!("no joltages in {row:?}")));
+    }
+
+    Ok((buttons, joltages))
+}
+
+// part 2 solved over the integers, without floating point rounding
+pub fn part2_exact(input: impl BufRead) -> Result<usize, Error> {
+    let mut min_pressed_total = 0;
+    for (i, row) in input.lines().enumerate() {
+        let (buttons, joltages) = parse_counters(&row?).map_err(|e| e.on_line(i + 1))?;
+
+        let Some(min_presses) = solve_exact(&buttons, &joltages)? else {
+            return Err(Error::NoAnswer(format!(
+                "machine {} can't reach its joltages",
+                i + 1
+            )));
+        };
+        min_pressed_total = add_presses(min_pressed_total, min_presses)?;
+    }
+
+    Ok(min_pressed_total)
+}
+
+// the augmented matrix [A | b] of a machine, one row per counter, after fraction-free
+// Gauss-Jordan elimination. every pivot column is zero outside its own row
+pub struct Reduced {
+    pub matrix: Vec<Vec<i128>>,
+    pub pivot_cols: Vec<usize>,
+}
+
+impl Reduced {
+    // one row per line, the targets after the bar
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for row in &self.matrix {
+            let (coefficients, target) = row.split_at(row.len() - 1);
+            let coefficients: Vec<String> = coefficients.iter().map(|c| c.to_string()).collect();
+            text += &format!("{} | {}\n", coefficients.join(" "), target[0]);
+        }
+        text
+    }
+}
+
+pub fn reduce(buttons: &[Vec<u8>], targets: &[usize]) -> Result<Reduced, Error> {
+    let num_buttons = buttons.len();
+
+    // augmented matrix [A | b], one row per counter
+    let mut matrix: Vec<Vec<i128>> = targets
+        .iter()
+        .enumerate()
+        .map(|(counter, &target)| {
+            let mut row: Vec<i128> = buttons
+                .iter()
+                .map(|button| button.contains(&(counter as u8)) as i128)
+                .collect();
+            row.push(target as i128);
+            row
+        })
+        .collect();
+
+    let mut pivot_cols = Vec::new();
+    for col in 0..num_buttons {
+        let pivot_row = pivot_cols.len();
+        let Some(row) = (pivot_row..matrix.len()).find(|&row| matrix[row][col] != 0) else {
+            continue;
+        };
+        matrix.swap(pivot_row, row);
+
+        // clear the column in every other row, staying in integers
+        for row in 0..matrix.len() {
+            let factor = matrix[row][col];
+            if row == pivot_row || factor == 0 {
+                continue;
+            }
+
+            let pivot = matrix[pivot_row][col];
+            for c in 0..=num_buttons {
+                matrix[row][c] = matrix[row][c] * pivot - matrix[pivot_row][c] * factor;
+            }
+
+            // keep the numbers small
+            let divisor = matrix[row].iter().fold(0, |g, &v| gcd(g, v.abs()));
+            if divisor > 1 {
+                matrix[row].iter_mut().for_each(|v| *v /= divisor);
+            }
+
+            // within an i64 the products of the next step and the search can't overflow
+            if matrix[row].iter().any(|v| v.abs() > i64::MAX as i128) {
+                return Err(Error::NoAnswer(
+                    "the numbers of the elimination grow too large".to_string(),
+                ));
+            }
+        }
+
+        pivot_cols.push(col);
+    }
+
+    Ok(Reduced { matrix, pivot_cols })
+}
+
+// fewest presses reaching the targets. the reduced matrix gives every pivot button as an
+// integer expression of the free buttons, which are searched, each pressed at most as
+// often as the smallest target it adds to. None when the targets can't be reached
+pub fn solve_exact(buttons: &[Vec<u8>], targets: &[usize]) -> Result<Option<usize>, Error> {
+    let num_buttons = buttons.len();
+    let Reduced { matrix, pivot_cols } = reduce(buttons, targets)?;
+
+    // rows without a pivot read 0 = target
+    let rank = pivot_cols.len();
+    if matrix[rank..].iter().any(|row| row[num_buttons] != 0) {
+        return Ok(None);
+    }
+
+    let free: Vec<usize> = (0..num_buttons)
+        .filter(|col| !pivot_cols.contains(col))
+        .collect();
+    let bounds: Vec<i128> = free
+        .iter()
+        .map(|&col| {
+            buttons[col]
+                .iter()
+                .filter_map(|&counter| targets.get(counter as usize))
+                .min()
+                .map_or(0, |&target| target as i128)
+        })
+        .collect();
+
+    let search = ExactSearch {
+        rows: &matrix[..rank],
+        pivot_cols: &pivot_cols,
+        free: &free,
+        bounds: &bounds,
+    };
+    let mut best = None;
+    search.run(&mut vec![0; free.len()], 0, &mut best);
+
+    Ok(best)
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+// the reduced rows and the free buttons to try presses for
+struct ExactSearch<'a> {
+    rows: &'a [Vec<i128>],
+    pivot_cols: &'a [usize],
+    free: &'a [usize],
+    bounds: &'a [i128],
+}
+
+impl ExactSearch<'_> {
+    // tries every number of presses for the free buttons from idx on
+    fn run(&self, presses: &mut Vec<i128>, idx: usize, best: &mut Option<usize>) {
+        let pressed: i128 = presses[..idx].iter().sum();
+        if best.is_some_and(|best| pressed >= best as i128) {
+            return;
+        }
+
+        if idx < self.free.len() {
+            for n in 0..=self.bounds[idx] {
+                presses[idx] = n;
+                self.run(presses, idx + 1, best);
+            }
+            presses[idx] = 0;
+            return;
+        }
+
+        // the pivot buttons follow, they must be pressed a whole, non negative number of times
+        let mut total = pressed;
+        for (row, &col) in self.rows.iter().zip(self.pivot_cols) {
+            let rest: i128 = self
+                .free
+                .iter()
+                .zip(presses.iter())
+                .map(|(&free_col, &n)| row[free_col] * n)
+                .sum();
+            let value = row[row.len() - 1] - rest;
+
+            if value % row[col] != 0 || value / row[col] < 0 {
+                return;
+            }
+            total += value / row[col];
+        }
+
+        if best.is_none_or(|best| total < best as i128) {
+            *best = Some(total as usize);
+        }
+    }
+}
+
+pub fn solve_linear(
+    buttons: &[Vec<u8>],
+    targets: &[usize],
+    num_counters: usize,
+    num_buttons: usize,
+) -> usize {
+    // Build augmented matrix [A | b] using rationals (as f64 for simplicity)
+    let mut matrix: Vec<Vec<f64>> = vec![vec![0.0; num_buttons + 1]; num_counters];
+
+    for counter in 0..num_counters {
+        for (btn_idx, button) in buttons.iter().enumerate() {
+            if button.contains(&(counter as u8)) {
+                matrix[counter][btn_idx] = 1.0;
+            }
+        }
+        matrix[counter][num_buttons] = targets[counter] as f64;
+    }
+
+    // Gaussian elimination with partial pivoting
+    let mut pivot_col = 0;
+    let mut pivot_row = 0;
+    let mut pivot_cols = vec![];
+
+    while pivot_row < num_counters && pivot_col < num_buttons {
+        // Find pivot
+        let mut max_row = pivot_row;
+        for row in pivot_row + 1..num_counters {
+            if matrix[row][pivot_col].abs() > matrix[max_row][pivot_col].abs() {
+                max_row = row;
+            }
+        }
+
+        if matrix[max_row][pivot_col].abs() < 1e-10 {
+            pivot_col += 1;
+            continue;
+        }
+
+        matrix.swap(pivot_row, max_row);
+        pivot_cols.push(pivot_col);
+
+        // Eliminate below
+        for row in pivot_row + 1..num_counters {
+            let factor = matrix[row][pivot_col] / matrix[pivot_row][pivot_col];
+            for col in pivot_col..=num_buttons {
+                matrix[row][col] -= factor * matrix[pivot_row][col];
+            }
+        }
+
+        pivot_row += 1;
+        pivot_col += 1;
+    }
+
+    let rank = pivot_cols.len();
+    let num_free = num_buttons - rank;
+
+    // Back substitution to get particular solution (with free vars = 0)
+    let mut solution = vec![0.0; num_buttons];
+
+    for i in (0..rank).rev() {
+        let pc = pivot_cols[i];
+        let mut sum = matrix[i][num_buttons];
+        for j in pc + 1..num_buttons {
+            sum -= matrix[i][j] * solution[j];
+        }
+        solution[pc] = sum / matrix[i][pc];
+    }
+
+    // Check for non-integer or negative solutions in basic vars
+    // If we have free variables, we need to search
+    if num_free == 0 {
+        // Unique solution - check if valid
+        let mut total = 0.0;
+        for &x in &solution {
+            if x < -1e-9 || (x - x.round()).abs() > 1e-9 {
+                return usize::MAX; // No valid solution
+            }
+            total += x.round();
+        }
+        return total as usize;
+    }
+
+    // We have free variables - need to search over them
+    // Free variables are columns not in pivot_cols
+    let free_vars: Vec<usize> = (0..num_buttons)
+        .filter(|c| !pivot_cols.contains(c))
+        .collect();
+
+    // For each free variable, find its coefficient in each basic variable
+    // Then search over free variable values
+
+    // Compute null space basis vectors
+    let mut null_basis: Vec<Vec<f64>> = vec![];
+
+    for &free_col in &free_vars {
+        let mut null_vec = vec![0.0; num_buttons];
+        null_vec[free_col] = 1.0;
+
+        // Back-substitute to find effect on pivot vars
+        for i in (0..rank).rev() {
+            let pc = pivot_cols[i];
+            let mut sum = 0.0;
+            for j in pc + 1..num_buttons {
+                sum += matrix[i][j] * null_vec[j];
+            }
+            null_vec[pc] = -sum / matrix[i][pc];
+        }
+        null_basis.push(null_vec);
+    }
+
+    // Search over combinations of free variables
+    // Limit search range based on targets
+    let max_search = targets.iter().max().unwrap_or(&0) + 1;
+
+    let mut best = usize::MAX;
+    search_free_vars(
+        &solution,
+        &null_basis,
+        0,
+        vec![0i64; num_free],
+        max_search as i64,
+        &mut best,
+    );
+
+    best
+}
+
+fn search_free_vars(
+    particular: &Vec<f64>,
+    null_basis: &Vec<Vec<f64>>,
+    idx: usize,
+    coeffs: Vec<i64>,
+    max_val: i64,
+    best: &mut usize,
+) {
+    if idx == null_basis.len() {
+        // Evaluate solution
+        let mut solution = particular.clone();
+        for (i, &c) in coeffs.iter().enumerate() {
+            for j in 0..solution.len() {
+                solution[j] += c as f64 * null_basis[i][j];
+            }
+        }
+
+        // Check validity and compute sum
+        let mut total = 0.0;
+        for &x in &solution {
+            let rounded = x.round();
+            if rounded < -0.5 || (x - rounded).abs() > 1e-6 {
+                return; // Invalid
+            }
+            if rounded < 0.0 {
+                return;
+            }
+            total += rounded;
+        }
+
+        *best = (*best).min(total as usize);
+        return;
+    }
+
+    // Try values for this free variable
+    // Estimate reasonable range
+    for c in -max_val..=max_val {
+        let mut new_coeffs = coeffs.clone();
+        new_coeffs[idx] = c;
+        search_free_vars(particular, null_basis, idx + 1, new_coeffs, max_val, best);
+    }
+}
+
+#[test]
+fn test_p1() {
+    let data = r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 7)
+}
+
+#[test]
+fn test_p2() {
+    let data = r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    assert_eq!(part2(data.as_bytes()).unwrap(), 33)
+}
+
+#[test]
+fn test_p1_wide_pattern() {
+    let data = r"[.................#] (17) (0,17) {1}";
+
+    assert_eq!(part1(data.as_bytes()).unwrap(), 1)
+}
+
+#[test]
+fn test_bad_machines() {
+    let lights = format!("[{}] (0) {{1}}", ".".repeat(65));
+    let buttons = format!("[.#] {} {{1}}", "(0) ".repeat(32));
+
+    assert!(part1(lights.as_bytes()).is_err());
+    assert!(part1("[.#] (64) {1}".as_bytes()).is_err());
+    assert!(part1(buttons.as_bytes()).is_err());
+    assert!(part1("[.#] (0) {1}".as_bytes()).is_err());
+    assert!(part2("[.#] (0 {1}".as_bytes()).is_err());
+    assert!(part2("[.#] (0) {1,x}".as_bytes()).is_err());
+    assert!(part2("[.#] (0)".as_bytes()).is_err());
+    assert!(part2("[.#] (0) {4294967296}".as_bytes()).is_err())
+}
+
+#[test]
+fn test_p2_exact() {
+    let data = r"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}
+[.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}";
+
+    assert_eq!(part2_exact(data.as_bytes()).unwrap(), 33);
+    // only button 0 adds to counter 1, and it adds to counter 0 just as much
+    assert!(part2_exact("[..] (0,1) (0) {1,2}".as_bytes()).is_err())
+}
+
+#[test]
+fn test_snapshot_reduced() {
+    let data = std::fs::read_to_string(Day10::KEY.fixture_path("example")).unwrap();
+
+    let reduced: Vec<String> = data
+        .lines()
+        .map(|row| {
+            let (buttons, joltages) = parse_counters(row).unwrap();
+            reduce(&buttons, &joltages).unwrap().to_text()
+        })
+        .collect();
+
+    aoc::snapshot::assert_snapshot(Day10::KEY, "reduced", &reduced.join("\n"))
+}
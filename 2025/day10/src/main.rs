@@ -0,0 +1,7 @@
+use aoc2025_day10::Day10;
+
+aoc::count_allocations!();
+
+fn main() {
+    aoc::main::<Day10>();
+}
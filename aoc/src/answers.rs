@@ -0,0 +1,72 @@
+use std::{collections::BTreeMap, fs};
+
+use crate::{Error, Key, root};
+
+// known answers per puzzle and input, input being the puzzle input or the name of a fixture.
+// kept in answers.txt at the root, one line per input:
+// <year> <day> <input|fixture name> <part 1> <part 2>, with - for an answer not known yet
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Answers {
+    entries: BTreeMap<(Key, String), [Option<String>; 2]>,
+}
+
+impl Answers {
+    pub fn load() -> Result<Answers, Error> {
+        let path = root().join("answers.txt");
+        let data =
+            fs::read_to_string(&path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+
+        Answers::parse(&data)
+    }
+
+    pub fn parse(data: &str) -> Result<Answers, Error> {
+        let mut answers = Answers::default();
+
+        for (i, line) in data.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [year, day, input, part1, part2] = fields[..] else {
+                return Err(Error::at_line(i + 1, "expected year day input part1 part2"));
+            };
+            let (Ok(year), Ok(day)) = (year.parse(), day.parse()) else {
+                return Err(Error::at_line(i + 1, "bad year or day"));
+            };
+
+            let known = |answer: &str| (answer != "-").then(|| answer.to_string());
+            answers.entries.insert(
+                (Key::new(year, day), input.to_string()),
+                [known(part1), known(part2)],
+            );
+        }
+
+        Ok(answers)
+    }
+
+    // answer of part 1 or 2 for the puzzle input ("input") or a fixture
+    pub fn get(&self, key: Key, input: &str, part: u8) -> Option<&str> {
+        let answers = self.entries.get(&(key, input.to_string()))?;
+        answers.get(usize::from(part).checked_sub(1)?)?.as_deref()
+    }
+}
+
+#[test]
+fn test_answers() {
+    let data = r"# year day input part1 part2
+2025 4 input 1437 8765
+2025 4 example 13 -
+";
+
+    let answers = Answers::parse(data).unwrap();
+    let key = Key::new(2025, 4);
+
+    assert_eq!(answers.get(key, "input", 2), Some("8765"));
+    assert_eq!(answers.get(key, "example", 1), Some("13"));
+    assert_eq!(answers.get(key, "example", 2), None);
+    assert_eq!(answers.get(key, "input", 3), None);
+    assert_eq!(answers.get(Key::new(2024, 4), "input", 1), None);
+    assert!(Answers::parse("2025 4 input 1").is_err())
+}
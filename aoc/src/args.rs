@@ -0,0 +1,90 @@
+use std::{env, fmt::Debug, path::PathBuf, str::FromStr};
+
+use crate::Key;
+
+// command line flags, either bare (--list) or followed by a value (--part 2)
+#[derive(Debug, Clone, Default)]
+pub struct Args {
+    args: Vec<String>,
+}
+
+impl Args {
+    pub fn new(args: Vec<String>) -> Self {
+        Args { args }
+    }
+
+    // without the program name
+    pub fn from_env() -> Self {
+        Args::new(env::args().skip(1).collect())
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.args.iter().any(|a| a == name)
+    }
+
+    // the argument after the flag
+    pub fn value(&self, name: &str) -> Option<&str> {
+        let i = self.args.iter().position(|a| a == name)?;
+        let value = self.args.get(i + 1);
+
+        Some(value.unwrap_or_else(|| panic!("{name} needs a value")))
+    }
+
+    pub fn parse<T: FromStr>(&self, name: &str) -> Option<T>
+    where
+        T::Err: Debug,
+    {
+        self.value(name).map(|v| {
+            v.parse()
+                .unwrap_or_else(|e| panic!("bad value {v:?} for {name}: {e:?}"))
+        })
+    }
+
+    // --part 1 or 2, both when not given
+    pub fn parts(&self) -> Vec<u8> {
+        match self.parse("--part") {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    // --input or the cached puzzle input
+    pub fn input_path(&self, key: Key) -> PathBuf {
+        match self.value("--input") {
+            Some(path) => PathBuf::from(path),
+            None => key.input_path(),
+        }
+    }
+
+    // arguments that aren't flags, with the flag values taken out
+    pub fn positional(&self, value_flags: &[&str]) -> Vec<&str> {
+        let mut positional = Vec::new();
+        let mut args = self.args.iter();
+
+        while let Some(arg) = args.next() {
+            if value_flags.contains(&arg.as_str()) {
+                args.next();
+            } else if !arg.starts_with('-') {
+                positional.push(arg.as_str());
+            }
+        }
+
+        positional
+    }
+}
+
+#[test]
+fn test_args() {
+    let args = Args::new(
+        ["run", "4", "--part", "2", "--list", "--input", "x.txt"]
+            .map(String::from)
+            .to_vec(),
+    );
+
+    assert!(args.flag("--list"));
+    assert!(!args.flag("--render"));
+    assert_eq!(args.value("--input"), Some("x.txt"));
+    assert_eq!(args.parts(), [2]);
+    assert_eq!(args.input_path(Key::new(2025, 4)), PathBuf::from("x.txt"));
+    assert_eq!(args.positional(&["--part", "--input"]), ["run", "4"])
+}
@@ -0,0 +1,117 @@
+use std::{fs, path::Path};
+
+use crate::Error;
+
+pub type Color = [u8; 3];
+
+// every grid cell is drawn as a square of this many pixels
+pub const CELL_SIZE: usize = 4;
+
+// a grid of coloured cells, written as binary PPM
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<Color>,
+}
+
+impl Grid {
+    pub fn new(width: usize, height: usize, background: Color) -> Self {
+        Grid {
+            width,
+            height,
+            cells: vec![background; width * height],
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Color {
+        self.cells[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        self.cells[y * self.width + x] = color;
+    }
+
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let (width, height) = (self.width * CELL_SIZE, self.height * CELL_SIZE);
+
+        let mut image = format!("P6\n{width} {height}\n255\n").into_bytes();
+        for y in 0..height {
+            for x in 0..width {
+                image.extend_from_slice(&self.get(x / CELL_SIZE, y / CELL_SIZE));
+            }
+        }
+
+        image
+    }
+}
+
+// what a day draws, grids as PPM and geometry as SVG
+#[derive(Debug, Clone, PartialEq)]
+pub enum Render {
+    Grid(Grid),
+    Svg(String),
+}
+
+impl Render {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Render::Grid(_) => "ppm",
+            Render::Svg(_) => "svg",
+        }
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Render::Grid(grid) => grid.to_ppm(),
+            Render::Svg(svg) => svg.clone().into_bytes(),
+        }
+    }
+}
+
+// the format follows from the extension of the path, one that doesn't match is refused
+// rather than writing e.g. PPM into a .svg file
+pub fn write(path: &Path, render: &Render) -> Result<(), Error> {
+    let extension = path.extension().and_then(|e| e.to_str());
+    if !extension.is_some_and(|e| e.eq_ignore_ascii_case(render.extension())) {
+        return Err(Error::Unsupported(format!(
+            "can't render to {}, this day draws .{} files",
+            path.display(),
+            render.extension()
+        )));
+    }
+
+    fs::write(path, render.to_bytes()).map_err(|e| Error::Io(format!("{}: {e}", path.display())))
+}
+
+#[test]
+fn test_grid() {
+    let mut grid = Grid::new(3, 2, [0, 0, 0]);
+    grid.set(2, 1, [255, 0, 0]);
+
+    let image = grid.to_ppm();
+    let header = b"P6\n12 8\n255\n";
+
+    assert_eq!(&image[..header.len()], header);
+    assert_eq!(image.len(), header.len() + 12 * 8 * 3);
+    assert_eq!(&image[image.len() - 3..], [255, 0, 0])
+}
+
+#[test]
+fn test_write_checks_extension() {
+    let grid = Render::Grid(Grid::new(1, 1, [0, 0, 0]));
+    let svg = Render::Svg("<svg/>".to_string());
+
+    assert!(matches!(
+        write(Path::new("out.svg"), &grid),
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(
+        write(Path::new("out.ppm"), &svg),
+        Err(Error::Unsupported(_))
+    ));
+    assert!(matches!(
+        write(Path::new("out"), &svg),
+        Err(Error::Unsupported(_))
+    ))
+}
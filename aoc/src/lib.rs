@@ -0,0 +1,379 @@
+// shared pieces of the puzzle solutions: the Solution trait every day implements, puzzles
+// keyed by (year, day) with their cached inputs, fixtures and known answers, the
+// command line runner used by the day binaries and the aoc runner alike, logging, image
+// export for the days that draw their result, allocation counting, snapshots of
+// intermediate results for the tests, and range helpers more than one day needs
+
+pub mod alloc;
+pub mod export;
+pub mod log;
+pub mod ranges;
+pub mod snapshot;
+
+mod answers;
+mod args;
+mod run;
+
+pub use answers::Answers;
+pub use args::Args;
+pub use export::Render;
+pub use run::{main, run};
+
+use std::{
+    fmt, fs,
+    io::{self, BufRead, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+};
+
+// a puzzle, days from different years can live side by side
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Key {
+    pub const fn new(year: u16, day: u8) -> Self {
+        Key { year, day }
+    }
+
+    // <root>/<year>/dayXX
+    pub fn dir(&self) -> PathBuf {
+        root()
+            .join(self.year.to_string())
+            .join(format!("day{:02}", self.day))
+    }
+
+    // the puzzle input is cached next to the solution, it is never fetched
+    pub fn input_path(&self) -> PathBuf {
+        self.dir().join("input.txt")
+    }
+
+    // example inputs from the puzzle text live in <dir>/fixtures/<name>.txt
+    pub fn fixture_path(&self, name: &str) -> PathBuf {
+        self.dir().join("fixtures").join(format!("{name}.txt"))
+    }
+
+    // text snapshots of intermediate results, see snapshot::assert_snapshot
+    pub fn snapshot_path(&self, name: &str) -> PathBuf {
+        self.dir().join("snapshots").join(format!("{name}.txt"))
+    }
+
+    // names of all fixtures of the day, sorted
+    pub fn fixtures(&self) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(self.dir().join("fixtures")) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                let name = path.file_name()?.to_str()?.strip_suffix(".txt")?;
+                Some(name.to_string())
+            })
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {:02}", self.year, self.day)
+    }
+}
+
+// the workspace root, this crate sits right below it
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("aoc crate is inside the workspace")
+        .to_path_buf()
+}
+
+// answers are compared and stored as text, whatever number type a day uses
+pub type Answer = String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    // input that doesn't follow the puzzle format
+    Parse(String),
+    // well formed input without an answer
+    NoAnswer(String),
+    // reading the input failed
+    Io(String),
+    // something the puzzle or the runner can't do, like drawing a day without a render
+    Unsupported(String),
+}
+
+impl Error {
+    pub fn at_line(line: usize, message: impl fmt::Display) -> Error {
+        Error::Parse(format!("line {line}: {message}"))
+    }
+
+    // an error about a single line, with the line it came from in front
+    pub fn on_line(self, line: usize) -> Error {
+        match self {
+            Error::Parse(message) => Error::at_line(line, message),
+            Error::NoAnswer(message) => Error::NoAnswer(format!("line {line}: {message}")),
+            e => e,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Parse(message) => write!(f, "bad input, {message}"),
+            Error::NoAnswer(message) => write!(f, "no answer, {message}"),
+            Error::Io(message) => write!(f, "can't read input, {message}"),
+            Error::Unsupported(message) => write!(f, "not supported, {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e.to_string())
+    }
+}
+
+// a day's puzzle, the input is parsed once and both parts are solved from it
+pub trait Solution {
+    const KEY: Key;
+
+    type Input;
+
+    // how each part is solved, a line each for aoc report
+    const ALGORITHMS: [&'static str; 2];
+
+    fn parse(data: &str) -> Result<Self::Input, Error>;
+
+    fn part1(input: &Self::Input) -> Result<Answer, Error>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, Error>;
+
+    // days that solve a part while reading the input line by line return Some, the
+    // runner then streams the input instead of reading and parsing all of it
+    fn stream(_part: u8, _input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        None
+    }
+
+    // other ways of solving the parts, for --variant
+    fn variants() -> Vec<Variant<Self::Input>> {
+        Vec::new()
+    }
+
+    // commands of the day in aoc repl
+    fn commands() -> Vec<Command<Self::Input>> {
+        Vec::new()
+    }
+
+    // modes of the day beside its parts, for the day's binary and aoc run
+    fn modes() -> Vec<Mode> {
+        Vec::new()
+    }
+
+    // a picture of the solution, for --render
+    fn render(_input: &Self::Input) -> Result<Render, Error> {
+        Err(Error::Unsupported(format!(
+            "{} has nothing to render",
+            Self::KEY
+        )))
+    }
+}
+
+// one way of solving a part, a day with more than one lists its default solver among them
+pub struct Variant<I> {
+    pub name: &'static str,
+    pub part: u8,
+    pub solve: fn(&I) -> Result<Answer, Error>,
+}
+
+// a repl command, run on the parsed input with the rest of the command line
+pub struct Command<I> {
+    pub name: &'static str,
+    pub usage: &'static str,
+    pub run: fn(&I, &str) -> Result<String, Error>,
+}
+
+// a mode of the day beside its parts, like other rules for the answers or a dump of what
+// the solver did. any of its flags picks it and it runs instead of the parts, reading the
+// input on its own
+pub struct Mode {
+    pub flags: &'static [&'static str],
+    // those of its flags that are followed by a value
+    pub values: &'static [&'static str],
+    pub usage: &'static str,
+    pub run: fn(&Args, &mut dyn Write) -> Result<(), Error>,
+}
+
+// object safe view of a Solution, so the runner can list puzzles and pick them by key
+pub trait Puzzle: Sync {
+    fn key(&self) -> Key;
+
+    fn parse(&self, data: &str) -> Result<Box<dyn Parsed>, Error>;
+
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>>;
+
+    // names of the variants of a part
+    fn variants(&self, part: u8) -> Vec<&'static str>;
+
+    // names and usage of the repl commands
+    fn commands(&self) -> Vec<(&'static str, &'static str)>;
+
+    // usage of the modes
+    fn modes(&self) -> Vec<&'static str>;
+
+    // the flags of the modes that are followed by a value, so they aren't taken for
+    // positional arguments
+    fn mode_values(&self) -> Vec<&'static str>;
+
+    // runs the first mode whose flags are given, None when there is none
+    fn run_mode(&self, args: &Args, out: &mut dyn Write) -> Option<Result<(), Error>>;
+
+    // how a part is solved, empty if there is no such part
+    fn algorithm(&self, part: u8) -> &'static str;
+}
+
+// an input parsed by its puzzle, kept around to solve the parts from
+pub trait Parsed {
+    fn solve(&self, part: u8) -> Result<Answer, Error>;
+
+    fn solve_variant(&self, part: u8, name: &str) -> Result<Answer, Error>;
+
+    fn command(&self, name: &str, args: &str) -> Result<String, Error>;
+
+    fn render(&self) -> Result<Render, Error>;
+}
+
+// a Solution as a Puzzle
+pub struct Day<S>(PhantomData<fn() -> S>);
+
+impl<S> Day<S> {
+    pub const fn new() -> Self {
+        Day(PhantomData)
+    }
+}
+
+impl<S> Default for Day<S> {
+    fn default() -> Self {
+        Day::new()
+    }
+}
+
+impl<S: Solution + 'static> Puzzle for Day<S> {
+    fn key(&self) -> Key {
+        S::KEY
+    }
+
+    fn parse(&self, data: &str) -> Result<Box<dyn Parsed>, Error> {
+        Ok(Box::new(Input::<S>(S::parse(data)?)))
+    }
+
+    fn stream(&self, part: u8, input: &mut dyn BufRead) -> Option<Result<Answer, Error>> {
+        S::stream(part, input)
+    }
+
+    fn variants(&self, part: u8) -> Vec<&'static str> {
+        S::variants()
+            .iter()
+            .filter(|variant| variant.part == part)
+            .map(|variant| variant.name)
+            .collect()
+    }
+
+    fn commands(&self) -> Vec<(&'static str, &'static str)> {
+        S::commands()
+            .iter()
+            .map(|command| (command.name, command.usage))
+            .collect()
+    }
+
+    fn modes(&self) -> Vec<&'static str> {
+        S::modes().iter().map(|mode| mode.usage).collect()
+    }
+
+    fn mode_values(&self) -> Vec<&'static str> {
+        S::modes()
+            .iter()
+            .flat_map(|mode| mode.values)
+            .copied()
+            .collect()
+    }
+
+    fn run_mode(&self, args: &Args, out: &mut dyn Write) -> Option<Result<(), Error>> {
+        let modes = S::modes();
+        let mode = modes
+            .iter()
+            .find(|mode| mode.flags.iter().any(|flag| args.flag(flag)))?;
+
+        Some((mode.run)(args, out))
+    }
+
+    fn algorithm(&self, part: u8) -> &'static str {
+        match part {
+            1 | 2 => S::ALGORITHMS[part as usize - 1],
+            _ => "",
+        }
+    }
+}
+
+struct Input<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for Input<S> {
+    fn solve(&self, part: u8) -> Result<Answer, Error> {
+        match part {
+            1 => S::part1(&self.0),
+            2 => S::part2(&self.0),
+            _ => Err(Error::NoAnswer(format!("there is no part {part}"))),
+        }
+    }
+
+    fn solve_variant(&self, part: u8, name: &str) -> Result<Answer, Error> {
+        let variants = S::variants();
+        let Some(variant) = variants
+            .iter()
+            .find(|variant| variant.part == part && variant.name == name)
+        else {
+            return Err(Error::Unsupported(format!(
+                "{} has no variant {name:?} of part {part}",
+                S::KEY
+            )));
+        };
+
+        (variant.solve)(&self.0)
+    }
+
+    fn command(&self, name: &str, args: &str) -> Result<String, Error> {
+        let commands = S::commands();
+        let Some(command) = commands.iter().find(|command| command.name == name) else {
+            return Err(Error::Unsupported(format!(
+                "{} has no command {name:?}",
+                S::KEY
+            )));
+        };
+
+        (command.run)(&self.0, args)
+    }
+
+    fn render(&self) -> Result<Render, Error> {
+        S::render(&self.0)
+    }
+}
+
+#[test]
+fn test_key_paths() {
+    let key = Key::new(2025, 4);
+
+    assert_eq!(key.to_string(), "2025 day 04");
+    assert!(key.input_path().ends_with("2025/day04/input.txt"));
+    assert!(
+        key.fixture_path("example")
+            .ends_with("2025/day04/fixtures/example.txt")
+    );
+    assert!(key.fixtures().contains(&"example".to_string()))
+}
@@ -0,0 +1,84 @@
+use std::{
+    env,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+use crate::Args;
+
+// how much goes to stderr, stdout only ever carries the answers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Warn,
+    Debug,
+    Trace,
+}
+
+// warnings are shown unless turned off
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+// AOC_LOG=off|warn|debug|trace, or -v for debug and -vv for trace, whichever says more
+pub fn init(args: &Args) {
+    let env_level = match env::var("AOC_LOG").as_deref() {
+        Ok("off") => Level::Off,
+        Ok("debug") => Level::Debug,
+        Ok("trace") => Level::Trace,
+        _ => Level::Warn,
+    };
+    let arg_level = if args.flag("-vv") {
+        Level::Trace
+    } else if args.flag("-v") {
+        Level::Debug
+    } else {
+        Level::Off
+    };
+
+    set_level(env_level.max(arg_level));
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Warn) {
+            eprintln!("warning: {}", format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[test]
+fn test_levels() {
+    assert!(Level::Off < Level::Warn && Level::Debug < Level::Trace);
+
+    set_level(Level::Debug);
+    assert!(enabled(Level::Warn) && enabled(Level::Debug));
+    assert!(!enabled(Level::Trace));
+
+    set_level(Level::Warn);
+    assert!(!enabled(Level::Debug))
+}
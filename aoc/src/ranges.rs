@@ -0,0 +1,37 @@
+use std::ops::RangeInclusive;
+
+use crate::debug;
+
+// sorted, with overlapping and duplicate ranges merged so every value is in at most one
+// range. ranges that only touch, like 1-2 and 3-4, stay apart
+pub fn merge_ranges<T: Ord + Copy>(
+    ranges: impl IntoIterator<Item = RangeInclusive<T>>,
+) -> Vec<RangeInclusive<T>> {
+    let mut ranges: Vec<RangeInclusive<T>> = ranges.into_iter().collect();
+    ranges.sort_by_key(|range| *range.start());
+
+    let mut merged: Vec<RangeInclusive<T>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            // overlaps the last merged range
+            Some(last) if *last.end() >= *range.start() => {
+                let end = *last.end().max(range.end());
+                *last = *last.start()..=end;
+            }
+            _ => merged.push(range),
+        }
+    }
+
+    debug!("merged into {} ranges", merged.len());
+
+    merged
+}
+
+#[test]
+fn test_merge_ranges() {
+    let merged = merge_ranges([16..=20, 3..=5, 10..=14, 12..=18, 3..=5, 21..=21u64]);
+
+    assert_eq!(merged, [3..=5, 10..=20, 21..=21]);
+    assert_eq!(merge_ranges([0..=usize::MAX, 5..=7]), [0..=usize::MAX]);
+    assert!(merge_ranges(Vec::<RangeInclusive<i32>>::new()).is_empty())
+}
@@ -0,0 +1,177 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufReader, BufWriter, Write},
+    path::Path,
+    process,
+    time::{Duration, Instant},
+};
+
+use crate::{Args, Day, Error, Parsed, Puzzle, Solution, alloc, export, log};
+
+// solves a puzzle and prints the answers, the same for a day's own binary and the aoc
+// runner. [--input <path>] reads another input, [--part <1|2>] solves only that part,
+// [--render <path>] draws the solution to an image of the format its extension names,
+// [--variant <name|all>] solves the parts that have variants with another one, or with
+// all of them timed and cross-checked. the flags of one of the day's modes run that
+// instead
+pub fn run(puzzle: &dyn Puzzle, args: &Args) -> Result<(), Error> {
+    let mut out = BufWriter::new(io::stdout());
+    if let Some(result) = puzzle.run_mode(args, &mut out) {
+        result?;
+        return Ok(out.flush()?);
+    }
+
+    let path = args.input_path(puzzle.key());
+    let io_error = |e| Error::Io(format!("{}: {e}", path.display()));
+
+    let variant = args.value("--variant");
+    if let Some(name) = variant {
+        let known = args.parts().into_iter().any(|part| {
+            let variants = puzzle.variants(part);
+            variants.contains(&name) || (name == "all" && !variants.is_empty())
+        });
+        if !known {
+            return Err(Error::Unsupported(format!(
+                "{} has no variant {name:?} of the parts asked for",
+                puzzle.key()
+            )));
+        }
+    }
+
+    // parsed on first use, parts that stream the input don't need it
+    let mut parsed = None;
+
+    for part in args.parts() {
+        let variants = puzzle.variants(part);
+        if let Some(name) = variant
+            && !variants.is_empty()
+        {
+            let parsed = parse(puzzle, &path, &mut parsed)?;
+            run_variants(parsed, part, name, &variants)?;
+            continue;
+        }
+
+        let mut input = BufReader::new(File::open(&path).map_err(io_error)?);
+        let phase = format!("part {part}");
+        let answer = match alloc::measure(|| puzzle.stream(part, &mut input)) {
+            (Some(answer), stats) => {
+                report(&phase, stats);
+                answer?
+            }
+            (None, _) => {
+                let parsed = parse(puzzle, &path, &mut parsed)?;
+                let (answer, stats) = alloc::measure(|| parsed.solve(part));
+                report(&phase, stats);
+                answer?
+            }
+        };
+
+        println!("Part {part}: {answer}");
+    }
+
+    if let Some(render_path) = args.value("--render") {
+        let render = parse(puzzle, &path, &mut parsed)?.render()?;
+        export::write(Path::new(render_path), &render)?;
+    }
+
+    Ok(())
+}
+
+// the named variant of a part, or all of them with their timings
+fn run_variants(parsed: &dyn Parsed, part: u8, name: &str, variants: &[&str]) -> Result<(), Error> {
+    let solve = |name: &str| {
+        let (answer, stats) = alloc::measure(|| parsed.solve_variant(part, name));
+        report(&format!("part {part} ({name})"), stats);
+        answer
+    };
+
+    if name != "all" {
+        println!("Part {part} ({name}): {}", solve(name)?);
+        return Ok(());
+    }
+
+    let mut answers = Vec::new();
+    let mut timings = Vec::new();
+    for &name in variants {
+        let start = Instant::now();
+        let answer = solve(name)?;
+        let elapsed = start.elapsed();
+        println!("Part {part} ({name}): {answer} in {elapsed:?}");
+        answers.push(answer);
+        timings.push((name, elapsed));
+    }
+
+    if answers.windows(2).all(|pair| pair[0] == pair[1]) {
+        println!("All variants agree");
+        println!("Relative to the fastest: {}", relative_speed(&timings));
+        Ok(())
+    } else {
+        Err(Error::NoAnswer(format!(
+            "the variants of part {part} disagree"
+        )))
+    }
+}
+
+// each variant's time as a multiple of the fastest one's, queue 1.00x, rescan 4.49x
+fn relative_speed(timings: &[(&str, Duration)]) -> String {
+    // a variant too fast to time counts as a nanosecond
+    let nanos = |elapsed: Duration| elapsed.as_nanos().max(1) as f64;
+    let fastest = timings
+        .iter()
+        .map(|&(_, elapsed)| nanos(elapsed))
+        .fold(f64::MAX, f64::min);
+
+    timings
+        .iter()
+        .map(|&(name, elapsed)| format!("{name} {:.2}x", nanos(elapsed) / fastest))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn parse<'a>(
+    puzzle: &dyn Puzzle,
+    path: &Path,
+    parsed: &'a mut Option<Box<dyn Parsed>>,
+) -> Result<&'a dyn Parsed, Error> {
+    if parsed.is_none() {
+        let data =
+            fs::read_to_string(path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+        let (result, stats) = alloc::measure(|| puzzle.parse(&data));
+        report("parse", stats);
+        *parsed = Some(result?);
+    }
+
+    Ok(parsed.as_deref().expect("parsed above"))
+}
+
+// with the counting allocator installed, the allocations of every phase go to stderr
+fn report(phase: &str, stats: alloc::Stats) {
+    if alloc::installed() {
+        eprintln!("{phase}: {stats}");
+    }
+}
+
+// entry point of a day's binary
+pub fn main<S: Solution + 'static>() {
+    let args = Args::from_env();
+    log::init(&args);
+
+    if let Err(e) = run(&Day::<S>::new(), &args) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+#[test]
+fn test_relative_speed() {
+    let timings = [
+        ("rescan", Duration::from_micros(449)),
+        ("queue", Duration::from_micros(100)),
+    ];
+
+    assert_eq!(relative_speed(&timings), "rescan 4.49x, queue 1.00x");
+    assert_eq!(
+        relative_speed(&[("a", Duration::ZERO), ("b", Duration::ZERO)]),
+        "a 1.00x, b 1.00x"
+    )
+}
@@ -0,0 +1,72 @@
+use std::{env, fs, path::Path};
+
+use crate::Key;
+
+// checks text of an intermediate result against <dir>/snapshots/<name>.txt and fails the
+// test when it differs. with UPDATE_SNAPSHOTS=1 the text is written as the new snapshot
+pub fn assert_snapshot(key: Key, name: &str, actual: &str) {
+    assert_file(&key.snapshot_path(name), actual);
+}
+
+// the same for any generated file that is checked in
+pub fn assert_file(path: &Path, actual: &str) {
+    if env::var("UPDATE_SNAPSHOTS").is_ok_and(|v| v == "1") {
+        fs::create_dir_all(path.parent().expect("snapshots are in a directory")).unwrap();
+        fs::write(path, actual).unwrap();
+        return;
+    }
+
+    let Ok(expected) = fs::read_to_string(path) else {
+        panic!(
+            "no snapshot {}, run with UPDATE_SNAPSHOTS=1 to write it",
+            path.display()
+        );
+    };
+
+    if let Some(diff) = diff(&expected, actual) {
+        panic!(
+            "snapshot {} differs, run with UPDATE_SNAPSHOTS=1 to accept the change\n{diff}",
+            path.display()
+        );
+    }
+}
+
+// the lines that differ, - for the expected and + for the actual one, None if equal
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    let mut diff = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (e, a) = (expected.get(i), actual.get(i));
+        if e == a {
+            continue;
+        }
+
+        if let Some(e) = e {
+            diff += &format!("{:>4} - {e}\n", i + 1);
+        }
+        if let Some(a) = a {
+            diff += &format!("{:>4} + {a}\n", i + 1);
+        }
+    }
+
+    if diff.is_empty() {
+        diff = "only the line endings differ\n".to_string();
+    }
+    Some(diff)
+}
+
+#[test]
+fn test_diff() {
+    assert_eq!(diff("a\nb\n", "a\nb\n"), None);
+    assert_eq!(
+        diff("a\nb\n", "a\nc\nd\n").unwrap(),
+        "   2 - b\n   2 + c\n   3 + d\n"
+    );
+    assert_eq!(diff("a\n", "a").unwrap(), "only the line endings differ\n")
+}
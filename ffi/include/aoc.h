@@ -0,0 +1,21 @@
+/* generated by aoc-ffi, see header() in ffi/src/lib.rs */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define AOC_OK 0
+#define AOC_UNKNOWN_PUZZLE 1
+#define AOC_BAD_INPUT 2
+#define AOC_NO_ANSWER 3
+#define AOC_BUFFER_TOO_SMALL 4
+#define AOC_PANIC 5
+
+/* solves part 1 or 2 of a puzzle from the input, and writes the answer or the error as a
+ * NUL terminated string to out, cut short to fit out_len bytes. returns AOC_OK when the
+ * whole answer is in out */
+int32_t aoc_solve(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input,
+                  size_t input_len, char *out, size_t out_len);
+
+#endif
@@ -0,0 +1,164 @@
+// a C interface to every solved puzzle: aoc_solve solves one part of a day from an input
+// buffer. include/aoc.h is generated from here, see header()
+
+use std::{
+    ffi::c_char,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+use aoc::{Answer, Error, Key};
+use aoc_runner::find;
+
+// what aoc_solve returns, AOC_OK with the answer in the output buffer, otherwise the
+// buffer holds what went wrong
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(i32)]
+pub enum Status {
+    Ok = 0,
+    UnknownPuzzle = 1,
+    BadInput = 2,
+    NoAnswer = 3,
+    BufferTooSmall = 4,
+    Panic = 5,
+}
+
+impl Status {
+    pub const ALL: [Status; 6] = [
+        Status::Ok,
+        Status::UnknownPuzzle,
+        Status::BadInput,
+        Status::NoAnswer,
+        Status::BufferTooSmall,
+        Status::Panic,
+    ];
+
+    // name of the C constant
+    pub fn name(self) -> &'static str {
+        match self {
+            Status::Ok => "AOC_OK",
+            Status::UnknownPuzzle => "AOC_UNKNOWN_PUZZLE",
+            Status::BadInput => "AOC_BAD_INPUT",
+            Status::NoAnswer => "AOC_NO_ANSWER",
+            Status::BufferTooSmall => "AOC_BUFFER_TOO_SMALL",
+            Status::Panic => "AOC_PANIC",
+        }
+    }
+}
+
+// the answer of a part, or why there is none. a panicking solution is caught, it must not
+// unwind into C
+pub fn solve(year: u16, day: u8, part: u8, input: &[u8]) -> Result<Answer, (Status, String)> {
+    let key = Key::new(year, day);
+    let Some(puzzle) = find(key) else {
+        return Err((Status::UnknownPuzzle, format!("{key} is not solved")));
+    };
+    let Ok(data) = str::from_utf8(input) else {
+        return Err((Status::BadInput, "input is not UTF-8".to_string()));
+    };
+
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse(data)?.solve(part)));
+    match solved {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(e @ (Error::Parse(_) | Error::Io(_)))) => Err((Status::BadInput, e.to_string())),
+        Ok(Err(e)) => Err((Status::NoAnswer, e.to_string())),
+        Err(_) => Err((Status::Panic, format!("{key} part {part} panicked"))),
+    }
+}
+
+/// Solves part 1 or 2 of a puzzle and writes the answer or the error as a NUL terminated
+/// string to `out`.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `out` to `out_len` writable bytes.
+/// Either may be NULL when its length is 0.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn aoc_solve(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    out: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    let input = match input_len {
+        0 => &[][..],
+        _ => unsafe { slice::from_raw_parts(input, input_len) },
+    };
+    let out = match out_len {
+        0 => &mut [][..],
+        _ => unsafe { slice::from_raw_parts_mut(out.cast::<u8>(), out_len) },
+    };
+
+    let (status, text) = match solve(year, day, part, input) {
+        Ok(answer) => (Status::Ok, answer),
+        Err((status, message)) => (status, message),
+    };
+
+    // the text and its NUL, cut short if it doesn't fit
+    let fits = text.len() < out.len();
+    let len = text.len().min(out.len().saturating_sub(1));
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+    if let Some(end) = out.get_mut(len) {
+        *end = 0;
+    }
+
+    match (status, fits) {
+        (Status::Ok, false) => Status::BufferTooSmall as i32,
+        _ => status as i32,
+    }
+}
+
+// include/aoc.h
+pub fn header() -> String {
+    let mut header = String::from(
+        "/* generated by aoc-ffi, see header() in ffi/src/lib.rs */
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+",
+    );
+
+    for status in Status::ALL {
+        header += &format!("#define {} {}\n", status.name(), status as i32);
+    }
+
+    header += "
+/* solves part 1 or 2 of a puzzle from the input, and writes the answer or the error as a
+ * NUL terminated string to out, cut short to fit out_len bytes. returns AOC_OK when the
+ * whole answer is in out */
+int32_t aoc_solve(uint16_t year, uint8_t day, uint8_t part, const uint8_t *input,
+                  size_t input_len, char *out, size_t out_len);
+
+#endif
+";
+
+    header
+}
+
+#[test]
+fn test_header() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("include/aoc.h");
+    aoc::snapshot::assert_file(&path, &header())
+}
+
+#[test]
+fn test_solve() {
+    let example = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+
+    assert_eq!(solve(2025, 5, 2, example.as_bytes()), Ok("14".to_string()));
+    assert_eq!(
+        solve(2025, 25, 1, b"").unwrap_err().0,
+        Status::UnknownPuzzle
+    );
+    assert_eq!(solve(2025, 5, 1, b"3-5").unwrap_err().0, Status::BadInput);
+    assert_eq!(
+        solve(2025, 5, 3, example.as_bytes()).unwrap_err().0,
+        Status::NoAnswer
+    )
+}
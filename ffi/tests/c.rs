@@ -0,0 +1,59 @@
+use std::{env, path::Path, process::Command};
+
+use aoc::Answers;
+use aoc_runner::PUZZLES;
+
+// builds tests/c/solve_all.c with the system cc against the cdylib, and checks what it
+// solves for every day's example fixture against answers.txt
+#[test]
+fn test_c_program() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // the cdylib is built next to the test binary, in target/<profile>/deps
+    let exe = env::current_exe().unwrap();
+    let lib_dir = exe.parent().unwrap();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("solve_all");
+
+    let cc = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    let status = Command::new(cc)
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror"])
+        .arg(manifest_dir.join("tests/c/solve_all.c"))
+        .arg("-I")
+        .arg(manifest_dir.join("include"))
+        .arg("-L")
+        .arg(lib_dir)
+        .arg(format!("-Wl,-rpath,{}", lib_dir.display()))
+        .arg("-laoc_ffi")
+        .arg("-o")
+        .arg(&program)
+        .status()
+        .unwrap();
+    assert!(status.success(), "cc failed");
+
+    let mut run = Command::new(&program);
+    for puzzle in PUZZLES {
+        let key = puzzle.key();
+        run.arg(key.year.to_string())
+            .arg(key.day.to_string())
+            .arg(key.fixture_path("example"));
+    }
+    let output = run.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let answers = Answers::load().unwrap();
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert_eq!(lines.len(), PUZZLES.len() * 2);
+
+    for puzzle in PUZZLES {
+        let key = puzzle.key();
+        for part in 1..=2 {
+            let expected = answers.get(key, "example", part).unwrap();
+            let line = format!("{} {} {part} {expected}", key.year, key.day);
+            assert!(lines.contains(&line.as_str()), "missing {line:?}");
+        }
+    }
+}
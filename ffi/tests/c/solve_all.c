@@ -0,0 +1,76 @@
+/* solves both parts of the inputs given as <year> <day> <path> triples through aoc_solve,
+ * printing "<year> <day> <part> <answer>" per part, and checks the error codes */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "aoc.h"
+
+static char *read_file(const char *path, size_t *len) {
+    FILE *file = fopen(path, "rb");
+    if (!file) {
+        return NULL;
+    }
+
+    fseek(file, 0, SEEK_END);
+    long size = ftell(file);
+    fseek(file, 0, SEEK_SET);
+
+    char *data = malloc(size > 0 ? size : 1);
+    *len = fread(data, 1, size, file);
+    fclose(file);
+    return data;
+}
+
+static int expect(int32_t got, int32_t expected, const char *what) {
+    if (got != expected) {
+        fprintf(stderr, "%s: got status %d, expected %d\n", what, got, expected);
+        return 1;
+    }
+    return 0;
+}
+
+int main(int argc, char **argv) {
+    char out[64];
+    int failed = 0;
+
+    for (int i = 1; i + 2 < argc; i += 3) {
+        uint16_t year = (uint16_t)atoi(argv[i]);
+        uint8_t day = (uint8_t)atoi(argv[i + 1]);
+
+        size_t len = 0;
+        char *input = read_file(argv[i + 2], &len);
+        if (!input) {
+            fprintf(stderr, "can't read %s\n", argv[i + 2]);
+            return 1;
+        }
+
+        for (uint8_t part = 1; part <= 2; part++) {
+            int32_t status =
+                aoc_solve(year, day, part, (const uint8_t *)input, len, out, sizeof out);
+            if (status == AOC_OK) {
+                printf("%d %d %d %s\n", year, day, part, out);
+            } else {
+                fprintf(stderr, "%d %d %d: status %d, %s\n", year, day, part, status, out);
+                failed = 1;
+            }
+        }
+
+        /* an answer cut short to the buffer, still NUL terminated */
+        char tiny[2] = {'x', 'x'};
+        int32_t status = aoc_solve(year, day, 1, (const uint8_t *)input, len, tiny, 1);
+        failed |= expect(status, AOC_BUFFER_TOO_SMALL, "tiny buffer");
+        failed |= tiny[0] != '\0';
+
+        free(input);
+    }
+
+    failed |= expect(aoc_solve(2025, 25, 1, NULL, 0, out, sizeof out), AOC_UNKNOWN_PUZZLE,
+                     "unknown puzzle");
+    failed |= expect(aoc_solve(2025, 1, 3, NULL, 0, out, sizeof out), AOC_NO_ANSWER,
+                     "no part 3");
+    failed |= expect(aoc_solve(2025, 8, 1, (const uint8_t *)"1,2", 3, out, sizeof out),
+                     AOC_BAD_INPUT, "bad input");
+
+    return failed;
+}
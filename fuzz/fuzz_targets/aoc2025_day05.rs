@@ -0,0 +1,9 @@
+#![no_main]
+
+use aoc::Key;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let puzzle = aoc_runner::find(Key::new(2025, 5)).unwrap();
+    aoc_runner::fuzz(puzzle, data);
+});
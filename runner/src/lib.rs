@@ -0,0 +1,135 @@
+// every solved puzzle in one place, so they can be listed, run and checked by (year, day)
+
+mod repl;
+mod report;
+
+pub use repl::repl;
+pub use report::{report, splice};
+
+use std::{
+    fs,
+    time::{Duration, Instant},
+};
+
+use aoc::{Answer, Answers, Day, Error, Key, Puzzle};
+
+pub static PUZZLES: &[&dyn Puzzle] = &[
+    &Day::<aoc2025_day01::Day01>::new(),
+    &Day::<aoc2025_day02::Day02>::new(),
+    &Day::<aoc2025_day03::Day03>::new(),
+    &Day::<aoc2025_day04::Day04>::new(),
+    &Day::<aoc2025_day05::Day05>::new(),
+    &Day::<aoc2025_day06::Day06>::new(),
+    &Day::<aoc2025_day07::Day07>::new(),
+    &Day::<aoc2025_day08::Day08>::new(),
+    &Day::<aoc2025_day09::Day09>::new(),
+    &Day::<aoc2025_day10::Day10>::new(),
+];
+
+pub fn find(key: Key) -> Option<&'static dyn Puzzle> {
+    PUZZLES.iter().copied().find(|puzzle| puzzle.key() == key)
+}
+
+// the year the runner picks when none is given
+pub fn latest_year() -> u16 {
+    PUZZLES
+        .iter()
+        .map(|puzzle| puzzle.key().year)
+        .max()
+        .unwrap_or(0)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Correct,
+    Wrong { expected: String, got: Answer },
+    // solved, but answers.txt doesn't know the answer yet
+    Unknown(Answer),
+    Failed(Error),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub key: Key,
+    pub input: String,
+    pub part: u8,
+    pub status: Status,
+    // parsing the input and solving the part
+    pub elapsed: Duration,
+}
+
+// solves both parts of the cached puzzle input ("input") or a fixture and compares
+// them to the known answers
+pub fn check(puzzle: &dyn Puzzle, answers: &Answers, input: &str) -> Vec<Check> {
+    let key = puzzle.key();
+    let path = match input {
+        "input" => key.input_path(),
+        fixture => key.fixture_path(fixture),
+    };
+
+    let start = Instant::now();
+    let parsed = fs::read_to_string(&path)
+        .map_err(|e| Error::Io(format!("{}: {e}", path.display())))
+        .and_then(|data| puzzle.parse(&data));
+    let parse_time = start.elapsed();
+
+    (1..=2)
+        .map(|part| {
+            let start = Instant::now();
+            let solved = match &parsed {
+                Ok(parsed) => parsed.solve(part),
+                Err(e) => Err(e.clone()),
+            };
+            let elapsed = parse_time + start.elapsed();
+
+            let status = match solved {
+                Err(e) => Status::Failed(e),
+                Ok(got) => match answers.get(key, input, part) {
+                    Some(expected) if expected == got => Status::Correct,
+                    Some(expected) => Status::Wrong {
+                        expected: expected.to_string(),
+                        got,
+                    },
+                    None => Status::Unknown(got),
+                },
+            };
+
+            Check {
+                key,
+                input: input.to_string(),
+                part,
+                status,
+                elapsed,
+            }
+        })
+        .collect()
+}
+
+// feeds raw bytes through every way a puzzle reads its input: both parts streamed, then
+// parsed and solved with every variant. for the fuzz targets, where only panics count,
+// malformed input has to come back as an error
+pub fn fuzz(puzzle: &dyn Puzzle, data: &[u8]) {
+    for part in 1..=2 {
+        let _ = puzzle.stream(part, &mut &data[..]);
+    }
+
+    let Ok(text) = str::from_utf8(data) else {
+        return;
+    };
+    let Ok(parsed) = puzzle.parse(text) else {
+        return;
+    };
+    for part in 1..=2 {
+        let _ = parsed.solve(part);
+        for variant in puzzle.variants(part) {
+            let _ = parsed.solve_variant(part, variant);
+        }
+    }
+}
+
+#[test]
+fn test_find() {
+    assert_eq!(find(Key::new(2025, 4)).unwrap().key(), Key::new(2025, 4));
+    assert!(find(Key::new(2025, 25)).is_none());
+    assert_eq!(latest_year(), 2025)
+}
@@ -0,0 +1,158 @@
+use std::{
+    fs,
+    io::{self, BufWriter},
+    process,
+};
+
+use aoc::{Answers, Args, Error, Key, Puzzle};
+use aoc_runner::{PUZZLES, Status, check, find, latest_year, repl, report, splice};
+
+const USAGE: &str = "usage: aoc list
+       aoc run <day> [--year <year>] [--part <1|2>] [--input <path>] [--render <path>]
+               [--variant <name|all>] [<flags of a mode of the day, see aoc list>]
+       aoc check [<day>] [--year <year>] [--fixtures]
+       aoc repl <day> [--year <year>] [--input <path>]
+       aoc report [--print]";
+
+aoc::count_allocations!();
+
+fn main() {
+    let args = Args::from_env();
+    aoc::log::init(&args);
+
+    // the values of the day's modes aren't positional either
+    let mut value_flags = vec!["--year", "--part", "--input", "--render", "--variant"];
+    value_flags.extend(PUZZLES.iter().flat_map(|puzzle| puzzle.mode_values()));
+    let positional = args.positional(&value_flags);
+
+    let result = match positional[..] {
+        ["list"] => {
+            list();
+            Ok(())
+        }
+        ["run", day] => puzzle(&args, day).and_then(|puzzle| aoc::run(puzzle, &args)),
+        ["check"] => check_all(&args, PUZZLES.to_vec()),
+        ["check", day] => puzzle(&args, day).and_then(|puzzle| check_all(&args, vec![puzzle])),
+        ["repl", day] => puzzle(&args, day).and_then(|puzzle| start_repl(&args, puzzle)),
+        ["report"] => write_report(&args),
+        _ => {
+            eprintln!("{USAGE}");
+            process::exit(2);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
+
+// the puzzle of a day in --year, the latest year by default
+fn puzzle(args: &Args, day: &str) -> Result<&'static dyn Puzzle, Error> {
+    let year = args.parse("--year").unwrap_or_else(latest_year);
+    let Ok(day) = day.parse() else {
+        return Err(Error::NoAnswer(format!("{day:?} is not a day")));
+    };
+
+    let key = Key::new(year, day);
+    find(key).ok_or_else(|| Error::NoAnswer(format!("{key} is not solved")))
+}
+
+// parses the day's input once and answers commands from stdin
+fn start_repl(args: &Args, puzzle: &dyn Puzzle) -> Result<(), Error> {
+    let path = args.input_path(puzzle.key());
+    let data =
+        fs::read_to_string(&path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+    let parsed = puzzle.parse(&data)?;
+
+    let mut out = BufWriter::new(io::stdout());
+    repl(puzzle, parsed.as_ref(), io::stdin().lock(), &mut out)?;
+    Ok(())
+}
+
+// solves every puzzle input and puts the table into the README, --print only prints it
+fn write_report(args: &Args) -> Result<(), Error> {
+    let table = report(PUZZLES, &Answers::load()?);
+    if args.flag("--print") {
+        print!("{table}");
+        return Ok(());
+    }
+
+    let path = aoc::root().join("README.md");
+    let readme =
+        fs::read_to_string(&path).map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+    fs::write(&path, splice(&readme, &table)?)
+        .map_err(|e| Error::Io(format!("{}: {e}", path.display())))?;
+    println!("report written to {}", path.display());
+    Ok(())
+}
+
+fn list() {
+    for puzzle in PUZZLES {
+        let key = puzzle.key();
+        let fixtures = key.fixtures();
+        let input = if key.input_path().exists() {
+            "input"
+        } else {
+            "no input"
+        };
+
+        let variants: Vec<String> = [1, 2]
+            .into_iter()
+            .filter_map(|part| {
+                let names = puzzle.variants(part);
+                (!names.is_empty()).then(|| format!(", part {part} variants: {}", names.join(", ")))
+            })
+            .collect();
+
+        println!(
+            "{key}  {input}, fixtures: {}{}",
+            fixtures.join(", "),
+            variants.concat()
+        );
+        for usage in puzzle.modes() {
+            println!("    {usage}");
+        }
+    }
+}
+
+// compares the answers of the puzzle inputs and fixtures to answers.txt,
+// --fixtures skips the puzzle inputs
+fn check_all(args: &Args, puzzles: Vec<&dyn Puzzle>) -> Result<(), Error> {
+    let answers = Answers::load()?;
+    let mut failed = 0;
+
+    for puzzle in puzzles {
+        let key = puzzle.key();
+        let mut inputs = key.fixtures();
+        if !args.flag("--fixtures") && key.input_path().exists() {
+            inputs.insert(0, "input".to_string());
+        }
+
+        for input in inputs {
+            for check in check(puzzle, &answers, &input) {
+                let status = match check.status {
+                    Status::Correct => "ok".to_string(),
+                    Status::Unknown(got) => format!("{got} (not in answers.txt)"),
+                    Status::Wrong { expected, got } => {
+                        failed += 1;
+                        format!("WRONG, got {got}, expected {expected}")
+                    }
+                    Status::Failed(e) => {
+                        failed += 1;
+                        format!("FAILED, {e}")
+                    }
+                };
+
+                println!("{key} {input} part {}: {status}", check.part);
+            }
+        }
+    }
+
+    if failed > 0 {
+        eprintln!("{failed} checks failed");
+        process::exit(1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+use std::io::{self, BufRead, Write};
+
+use aoc::{Parsed, Puzzle};
+
+const BUILTIN: [(&str, &str); 3] = [
+    ("part", "part <1|2>  solve a part"),
+    ("help", "help  list the commands"),
+    ("quit", "quit  leave, like end of input"),
+];
+
+// reads commands line by line and answers them from the parsed input: the parts, and the
+// commands of the day. errors are printed and the repl carries on
+pub fn repl(
+    puzzle: &dyn Puzzle,
+    parsed: &dyn Parsed,
+    input: impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<()> {
+    let commands = puzzle.commands();
+    write!(out, "{} (help lists the commands)\n> ", puzzle.key())?;
+    out.flush()?;
+
+    for line in input.lines() {
+        let line = line?;
+        let (name, args) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+
+        let reply = match name {
+            "" => None,
+            "quit" => break,
+            "help" => Some(Ok(BUILTIN
+                .iter()
+                .chain(commands.iter())
+                .map(|(_, usage)| *usage)
+                .collect::<Vec<_>>()
+                .join("\n"))),
+            "part" => Some(match args.trim().parse() {
+                Ok(part) => parsed.solve(part),
+                Err(_) => Err(aoc::Error::Parse(format!("{args:?} is not a part"))),
+            }),
+            _ => Some(parsed.command(name, args.trim())),
+        };
+
+        match reply {
+            Some(Ok(reply)) => writeln!(out, "{reply}")?,
+            Some(Err(e)) => writeln!(out, "error: {e}")?,
+            None => (),
+        }
+        write!(out, "> ")?;
+        out.flush()?;
+    }
+
+    writeln!(out)
+}
+
+#[test]
+fn test_repl() {
+    let puzzle = crate::find(aoc::Key::new(2025, 5)).unwrap();
+    let parsed = puzzle
+        .parse("3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n")
+        .unwrap();
+
+    let mut out = Vec::new();
+    let input = "part 2\nmerged\nnope\n\nquit\npart 1\n";
+    repl(puzzle, parsed.as_ref(), input.as_bytes(), &mut out).unwrap();
+
+    let out = String::from_utf8(out).unwrap();
+    let replies: Vec<&str> = out.lines().skip(1).collect();
+    assert_eq!(
+        replies,
+        [
+            "> 14",
+            "> 3-5",
+            "10-20",
+            "2 ranges, 14 ids",
+            "> error: not supported, 2025 day 05 has no command \"nope\"",
+            "> > "
+        ]
+    )
+}
@@ -0,0 +1,92 @@
+use std::time::Duration;
+
+use aoc::{Answers, Error, Puzzle};
+
+use crate::{Status, check};
+
+const START: &str = "<!-- report:start -->";
+const END: &str = "<!-- report:end -->";
+
+// a markdown table of every part of the puzzles solved from their inputs, checked against
+// answers.txt. the answers themselves stay out of it
+pub fn report(puzzles: &[&dyn Puzzle], answers: &Answers) -> String {
+    let mut table =
+        String::from("| Day | Part | Status | Runtime | Algorithm |\n|---|---|---|---:|---|\n");
+
+    for &puzzle in puzzles {
+        let key = puzzle.key();
+        let checks = if key.input_path().exists() {
+            check(puzzle, answers, "input")
+        } else {
+            Vec::new()
+        };
+
+        for part in 1..=2 {
+            let (status, runtime) = match checks.iter().find(|check| check.part == part) {
+                None => ("no input", "-".to_string()),
+                Some(check) => {
+                    let status = match check.status {
+                        Status::Correct => "verified",
+                        Status::Unknown(_) => "unverified",
+                        Status::Wrong { .. } => "wrong",
+                        Status::Failed(_) => "failed",
+                    };
+                    (status, runtime(check.elapsed))
+                }
+            };
+
+            table.push_str(&format!(
+                "| {key} | {part} | {status} | {runtime} | {} |\n",
+                puzzle.algorithm(part)
+            ));
+        }
+    }
+
+    table
+}
+
+// text with the part between the report markers replaced by table
+pub fn splice(text: &str, table: &str) -> Result<String, Error> {
+    let (Some(start), Some(end)) = (text.find(START), text.find(END)) else {
+        return Err(Error::Unsupported(format!(
+            "no {START} and {END} to put the report between"
+        )));
+    };
+    if end < start {
+        return Err(Error::Unsupported(format!("{END} comes before {START}")));
+    }
+
+    let before = &text[..start + START.len()];
+    Ok(format!("{before}\n{table}{}", &text[end..]))
+}
+
+fn runtime(elapsed: Duration) -> String {
+    if elapsed < Duration::from_millis(1) {
+        format!("{} µs", elapsed.as_micros())
+    } else if elapsed < Duration::from_secs(1) {
+        format!("{:.1} ms", elapsed.as_secs_f64() * 1000.0)
+    } else {
+        format!("{:.2} s", elapsed.as_secs_f64())
+    }
+}
+
+#[test]
+fn test_splice() {
+    let text = "# aoc\n\n<!-- report:start -->\nold\n<!-- report:end -->\n\nmore\n";
+    let spliced = splice(text, "| new |\n").unwrap();
+
+    assert_eq!(
+        spliced,
+        "# aoc\n\n<!-- report:start -->\n| new |\n<!-- report:end -->\n\nmore\n"
+    );
+    assert_eq!(splice(&spliced, "| new |\n").unwrap(), spliced);
+    assert!(splice("# aoc\n", "| new |\n").is_err());
+    assert!(splice("<!-- report:end -->\n<!-- report:start -->\n", "").is_err())
+}
+
+#[test]
+fn test_runtime() {
+    assert_eq!(runtime(Duration::from_micros(250)), "250 µs");
+    assert_eq!(runtime(Duration::from_micros(12_340)), "12.3 ms");
+    assert_eq!(runtime(Duration::from_millis(7_490)), "7.49 s")
+}
@@ -0,0 +1,38 @@
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+};
+
+use aoc::Key;
+use aoc_runner::{find, fuzz};
+
+// the inputs that crashed a day once, kept where cargo fuzz puts them, under
+// fuzz/artifacts/<target>/ with the targets named aocYYYY_dayXX. they must come back
+// as answers or errors now, never as panics
+#[test]
+fn test_crashes() {
+    let mut crashed = Vec::new();
+
+    for target in fs::read_dir(aoc::root().join("fuzz/artifacts")).unwrap() {
+        let target = target.unwrap().path();
+        let name = target.file_name().unwrap().to_string_lossy().into_owned();
+        let key = name
+            .strip_prefix("aoc")
+            .and_then(|name| name.split_once("_day"))
+            .and_then(|(year, day)| Some(Key::new(year.parse().ok()?, day.parse().ok()?)));
+        let Some(puzzle) = key.and_then(find) else {
+            panic!("{name} is not the target of a solved day");
+        };
+
+        for artifact in fs::read_dir(&target).unwrap() {
+            let path = artifact.unwrap().path();
+            let data = fs::read(&path).unwrap();
+
+            if panic::catch_unwind(AssertUnwindSafe(|| fuzz(puzzle, &data))).is_err() {
+                crashed.push(path.display().to_string());
+            }
+        }
+    }
+
+    assert!(crashed.is_empty(), "still crashing: {crashed:?}")
+}
@@ -0,0 +1,63 @@
+use std::fs;
+
+use aoc::Answers;
+use aoc_runner::{PUZZLES, Status, check};
+
+// every day has an example fixture and solves it to the answers in answers.txt
+#[test]
+fn test_fixtures() {
+    let answers = Answers::load().unwrap();
+
+    for puzzle in PUZZLES {
+        let fixtures = puzzle.key().fixtures();
+        assert!(!fixtures.is_empty(), "{} has no fixtures", puzzle.key());
+
+        for fixture in fixtures {
+            for check in check(*puzzle, &answers, &fixture) {
+                assert_eq!(check.status, Status::Correct, "{check:?}");
+            }
+        }
+    }
+}
+
+// days that stream their input give the same answers as when parsing it whole
+#[test]
+fn test_streamed_fixtures() {
+    let answers = Answers::load().unwrap();
+
+    for puzzle in PUZZLES {
+        let key = puzzle.key();
+        for fixture in key.fixtures() {
+            let data = fs::read_to_string(key.fixture_path(&fixture)).unwrap();
+
+            for part in 1..=2 {
+                if let Some(answer) = puzzle.stream(part, &mut data.as_bytes()) {
+                    let expected = answers.get(key, &fixture, part);
+                    assert_eq!(answer.ok().as_deref(), expected, "{key} {fixture} {part}");
+                }
+            }
+        }
+    }
+}
+
+// every variant of a part gives the known answer
+#[test]
+fn test_variant_fixtures() {
+    let answers = Answers::load().unwrap();
+
+    for puzzle in PUZZLES {
+        let key = puzzle.key();
+        for fixture in key.fixtures() {
+            let data = fs::read_to_string(key.fixture_path(&fixture)).unwrap();
+            let parsed = puzzle.parse(&data).unwrap();
+
+            for part in 1..=2 {
+                for name in puzzle.variants(part) {
+                    let answer = parsed.solve_variant(part, name);
+                    let expected = answers.get(key, &fixture, part);
+                    assert_eq!(answer.ok().as_deref(), expected, "{key} {fixture} {name}");
+                }
+            }
+        }
+    }
+}